@@ -0,0 +1,56 @@
+//! Compares the automaton's backends on day 17's pocket dimension, for generated slices from
+//! about as big as a real input to much bigger. Run with `cargo bench --bench automaton`.
+
+use aoc::{
+    automaton::{Automaton, Backend, LIFE},
+    day_solver::DaySolver,
+    gen,
+    years::y2020::day17::Day17,
+};
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+
+const BACKENDS: [(&str, Backend); 3] = [
+    ("sparse", Backend::Sparse),
+    ("dense", Backend::Dense),
+    ("auto", Backend::Auto),
+];
+
+fn backends(c: &mut Criterion) {
+    let generator = gen::get(2020, 17).unwrap();
+
+    for size in [8, 32, 64] {
+        let input = generator.generate(0, size);
+        let slice = <Day17>::parse(input.trim()).unwrap();
+        let slice4: rustc_hash::FxHashSet<_> =
+            slice.iter().map(|&[x, y, z]| [x, y, z, 0]).collect();
+
+        let mut group = c.benchmark_group("2020 Day 17 backends");
+        if size > 8 {
+            // The sparse backend takes a good fraction of a second a run by then.
+            group.sample_size(10);
+        }
+        for (name, backend) in BACKENDS {
+            let pocket3 = Automaton::<3, 26>::new(LIFE).mirrored(1).backend(backend);
+            group.bench_with_input(
+                BenchmarkId::new(format!("3D {name}"), size),
+                &slice,
+                |b, s| {
+                    b.iter_batched(|| s.clone(), |s| pocket3.run(s), BatchSize::SmallInput);
+                },
+            );
+
+            let pocket4 = Automaton::<4, 80>::new(LIFE).mirrored(2).backend(backend);
+            group.bench_with_input(
+                BenchmarkId::new(format!("4D {name}"), size),
+                &slice4,
+                |b, s| {
+                    b.iter_batched(|| s.clone(), |s| pocket4.run(s), BatchSize::SmallInput);
+                },
+            );
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, backends);
+criterion_main!(benches);
@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc::years::y2020::day10::Day10;
+
+libfuzzer_sys::fuzz_target!(|input: &str| aoc_fuzz::solve::<Day10>(input));
@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc::years::y2020::day13::Day13;
+
+libfuzzer_sys::fuzz_target!(|input: &str| aoc_fuzz::solve::<Day13>(input));
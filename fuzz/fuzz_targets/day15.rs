@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc::years::y2020::day15::Day15;
+
+libfuzzer_sys::fuzz_target!(|input: &str| aoc_fuzz::solve::<Day15>(input));
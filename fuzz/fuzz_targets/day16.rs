@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc::years::y2020::day16::Day16;
+
+libfuzzer_sys::fuzz_target!(|input: &str| aoc_fuzz::solve::<Day16>(input));
@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc::years::y2020::day19::Day19;
+
+libfuzzer_sys::fuzz_target!(|input: &str| aoc_fuzz::solve::<Day19>(input));
@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc::years::y2020::day23::Day23;
+
+libfuzzer_sys::fuzz_target!(|input: &str| aoc_fuzz::solve::<Day23>(input));
@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc::years::y2020::day9::Day9;
+
+libfuzzer_sys::fuzz_target!(|input: &str| aoc_fuzz::solve::<Day9>(input));
@@ -0,0 +1,14 @@
+#!/bin/sh
+# Fills each target's corpus with the examples from the tests and the real input. Then fuzz a
+# day with `cargo +nightly fuzz run day14`.
+set -e
+cd "$(dirname "$0")"
+
+for seeds in seeds/day*; do
+    day=$(basename "$seeds")
+    mkdir -p "corpus/$day"
+    cp "$seeds"/* "corpus/$day/"
+    if [ -f "../input/2020/$day.txt" ]; then
+        cp "../input/2020/$day.txt" "corpus/$day/input.txt"
+    fi
+done
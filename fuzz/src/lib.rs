@@ -0,0 +1,19 @@
+use aoc::{day_solver::DaySolver, registry};
+
+/// Parses `input` the way the runner would and runs every part on it. Errors are fine, it's
+/// panics that the fuzzer is looking for.
+///
+/// Part 2 of the days the registry marks slow is left out, since it takes longer than libFuzzer
+/// waits for on almost any input.
+pub fn solve<'a, S: DaySolver<'a>>(input: &'a str) {
+    let slow = registry::get(S::YEAR, S::DAY).is_some_and(registry::Solver::is_slow);
+    if let Ok(data) = S::parse(input.trim()) {
+        let _ = S::part1(&data);
+        if !slow {
+            let _ = S::part2(&data);
+            if S::SOLVES_BOTH {
+                let _ = S::solve_both(&data);
+            }
+        }
+    }
+}
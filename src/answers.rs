@@ -0,0 +1,170 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{self, Read},
+    path::Path,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::registry::Answers;
+
+/// Verified answers, keyed by the hash of the puzzle input they belong to.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnswerBook {
+    #[serde(flatten)]
+    entries: BTreeMap<String, Entry>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    pub day: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+impl Entry {
+    /// The answer for `part`, which is 1 or 2.
+    #[must_use]
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// FNV-1a, which is stable across platforms and builds unlike `DefaultHasher`.
+#[must_use]
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(FNV_OFFSET, fnv);
+    format!("{hash:016x}")
+}
+
+/// Like [`input_hash`] of the trimmed contents of `reader`, which is read a chunk at a time so
+/// none of it has to be held at once. Only ASCII whitespace is trimmed, which is all inputs have.
+///
+/// # Errors
+///
+/// If `reader` fails.
+pub fn reader_hash(mut reader: impl Read) -> io::Result<String> {
+    let mut hash = FNV_OFFSET;
+    let mut started = false;
+    // Whitespace that only counts if something else comes after it.
+    let mut pending = Vec::new();
+    let mut buf = [0; 8192];
+
+    loop {
+        let read = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        for &b in &buf[..read] {
+            if b.is_ascii() && char::from(b).is_whitespace() {
+                if started {
+                    pending.push(b);
+                }
+            } else {
+                started = true;
+                hash = pending.iter().copied().fold(hash, fnv);
+                pending.clear();
+                hash = fnv(hash, b);
+            }
+        }
+    }
+
+    Ok(format!("{hash:016x}"))
+}
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+
+fn fnv(hash: u64, b: u8) -> u64 {
+    (hash ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+}
+
+impl AnswerBook {
+    /// Reads the answers at `path`, treating a missing file as an empty book.
+    ///
+    /// # Errors
+    ///
+    /// If the file can't be read or isn't a book of answers.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(s) => toml::from_str(&s).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Writes the answers to `path`.
+    ///
+    /// # Errors
+    ///
+    /// If the file can't be written.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let s = toml::to_string(self).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(path, s)
+    }
+
+    /// The known answers for `input`.
+    #[must_use]
+    pub fn get(&self, input: &str) -> Option<&Entry> {
+        self.get_by_hash(&input_hash(input))
+    }
+
+    /// The known answers for the input with this [`input_hash`].
+    #[must_use]
+    pub fn get_by_hash(&self, hash: &str) -> Option<&Entry> {
+        self.entries.get(hash)
+    }
+
+    /// Stores the given answers for `input`, keeping any known answers for parts that weren't
+    /// run. Returns whether anything changed.
+    pub fn record(&mut self, day: u8, input: &str, answers: &Answers) -> bool {
+        let entry = self.entries.entry(input_hash(input)).or_default();
+        let old = entry.clone();
+
+        entry.day = day;
+        let [part1, part2] = answers.clone();
+        entry.part1 = part1.or_else(|| entry.part1.take());
+        entry.part2 = part2.or_else(|| entry.part2.take());
+
+        *entry != old
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut book = AnswerBook::default();
+        assert!(book.record(8, "nop +0", &[Some("5".into()), None]));
+        assert!(book.record(8, "nop +0", &[None, Some("8".into())]));
+        assert!(!book.record(8, "nop +0", &[Some("5".into()), None]));
+
+        let s = toml::to_string(&book).unwrap();
+        assert_eq!(toml::from_str::<AnswerBook>(&s).unwrap(), book);
+
+        let entry = book.get("nop +0").unwrap();
+        assert_eq!((entry.get(1), entry.get(2)), (Some("5"), Some("8")));
+        assert!(book.get("nop +1").is_none());
+    }
+
+    #[test]
+    fn reader_hash_trims() {
+        for input in ["nop +0", "\n nop +0\r\n", "nop +0\n\n", "a \n\tb"] {
+            assert_eq!(
+                reader_hash(input.as_bytes()).unwrap(),
+                input_hash(input.trim()),
+                "{input:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,549 @@
+//! Cellular automata on an unbounded grid in any number of dimensions, like Conway's Game of
+//! Life and its 3D and 4D cousins in 2020 day 17.
+
+use std::str::FromStr;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+pub type Coord = i32;
+
+/// A cell's position along each of the `D` axes.
+pub type Cell<const D: usize> = [Coord; D];
+
+/// Every offset from -1 to 1 along each of the `D` axes, except staying put. There are `K` of
+/// them.
+///
+/// # Panics
+///
+/// If `K` isn't 3<sup>D</sup> - 1, which is at compile time when it's used in a constant.
+#[must_use]
+pub const fn neighborhood<const D: usize, const K: usize>() -> [Cell<D>; K] {
+    assert!(
+        K + 1 == 3usize.pow(D as u32),
+        "a cell has 3^D - 1 neighbours"
+    );
+
+    let mut offsets = [[0; D]; K];
+    let mut k = 0;
+    // Counting in base 3 with digits from -1 to 1, skipping the middle where every digit is 0.
+    let mut n = 0;
+    while n <= K {
+        if n != K / 2 {
+            let mut digits = n;
+            let mut d = 0;
+            while d < D {
+                offsets[k][D - 1 - d] = (digits % 3) as Coord - 1;
+                digits /= 3;
+                d += 1;
+            }
+            k += 1;
+        }
+        n += 1;
+    }
+    offsets
+}
+
+/// Which neighbour counts bring a dead cell to life and keep a live one alive, as in the B3/S23
+/// notation for Conway's rule.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Rule {
+    birth: u128,
+    survive: u128,
+}
+
+/// Conway's rule, which day 17 uses in 3D and 4D too.
+pub const LIFE: Rule = Rule::new(&[3], &[2, 3]);
+
+impl Rule {
+    /// # Panics
+    ///
+    /// If a count is 128 or more, or a cell could be born with no neighbours, since then the
+    /// space would fill up forever.
+    #[must_use]
+    pub const fn new(birth: &[u32], survive: &[u32]) -> Self {
+        Self {
+            birth: Self::mask(birth, false),
+            survive: Self::mask(survive, true),
+        }
+    }
+
+    const fn mask(counts: &[u32], zero: bool) -> u128 {
+        let mut mask = 0;
+        let mut i = 0;
+        while i < counts.len() {
+            assert!(counts[i] < 128, "neighbour counts in rules are below 128");
+            assert!(
+                zero || counts[i] != 0,
+                "cells can't be born with no neighbours"
+            );
+            mask |= 1 << counts[i];
+            i += 1;
+        }
+        mask
+    }
+
+    /// Whether a cell that's `alive` now with `count` live neighbours is alive next generation.
+    #[must_use]
+    pub const fn next(&self, alive: bool, count: u32) -> bool {
+        let mask = if alive { self.survive } else { self.birth };
+        count < 128 && mask & (1 << count) != 0
+    }
+}
+
+/// How an [`Automaton`] keeps track of its cells.
+///
+/// A few generations don't spread a crowded start far, so flat arrays beat hashing there. A few
+/// cells scattered across a big box would leave them scanning empty space instead.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Backend {
+    /// A hash set of the live cells, with neighbour counts in a hash map. Only the cells near
+    /// live ones take any room.
+    Sparse,
+    /// Flat arrays covering every cell that could come alive in the generations there are,
+    /// which avoids hashing but takes room for the whole box, and time to scan it every
+    /// generation.
+    Dense,
+    /// Dense when the box is small next to the neighbour updates the live cells make, and
+    /// sparse when they're spread thin across it, decided afresh each run.
+    Auto,
+}
+
+impl Backend {
+    pub const ALL: [Self; 3] = [Self::Sparse, Self::Dense, Self::Auto];
+}
+
+/// A [`Backend`] picked by type, so a solver can be built for each one.
+pub trait Pick: Send + Sync + 'static {
+    const BACKEND: Backend;
+}
+
+/// The types that pick each [`Backend`].
+pub mod pick {
+    use super::{Backend, Pick};
+
+    pub struct Sparse;
+    pub struct Dense;
+    pub struct Auto;
+
+    impl Pick for Sparse {
+        const BACKEND: Backend = Backend::Sparse;
+    }
+
+    impl Pick for Dense {
+        const BACKEND: Backend = Backend::Dense;
+    }
+
+    impl Pick for Auto {
+        const BACKEND: Backend = Backend::Auto;
+    }
+}
+
+impl FromStr for Backend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sparse" => Ok(Self::Sparse),
+            "dense" => Ok(Self::Dense),
+            "auto" => Ok(Self::Auto),
+            _ => Err(format!("`{s}` isn't one of sparse, dense or auto")),
+        }
+    }
+}
+
+/// How many times bigger than the live cells' neighbourhoods the box can be for the dense
+/// backend to still be worth it. Around here the two take about as long on day 17's pocket,
+/// and much further out the dense one scans a mostly empty box.
+const DENSE_RATIO: usize = 32;
+
+/// The box the dense backend covers.
+struct Bounds<const D: usize> {
+    /// Where cells can come alive.
+    inner: [(i64, i64); D],
+    /// The lowest corner of the box, which has room around `inner` for neighbours.
+    low: [i64; D],
+    lens: [usize; D],
+}
+
+/// Runs a [`Rule`] in `D` dimensions, where each cell has `K` neighbours. `K` has to be
+/// 3<sup>D</sup> - 1, which [`neighborhood`] checks when this is built.
+///
+/// Space ends where a [`Coord`] does, so cells at its edges have fewer neighbours.
+#[derive(Copy, Clone, Debug)]
+pub struct Automaton<const D: usize, const K: usize> {
+    rule: Rule,
+    generations: usize,
+    mirrored: usize,
+    backend: Backend,
+}
+
+impl<const D: usize, const K: usize> Automaton<D, K> {
+    const OFFSETS: [Cell<D>; K] = neighborhood::<D, K>();
+
+    /// Runs `rule` for 6 generations, like day 17.
+    #[must_use]
+    pub const fn new(rule: Rule) -> Self {
+        let _ = Self::OFFSETS;
+        Self {
+            rule,
+            generations: 6,
+            mirrored: 0,
+            backend: Backend::Sparse,
+        }
+    }
+
+    #[must_use]
+    pub const fn backend(mut self, backend: Backend) -> Self {
+        self.backend = backend;
+        self
+    }
+
+    #[must_use]
+    pub const fn generations(mut self, generations: usize) -> Self {
+        self.generations = generations;
+        self
+    }
+
+    /// Treats the last `axes` axes as mirrored at 0, which holds when the cells start out
+    /// symmetric on them, as they do when they're all at 0 there. Only cells at 0 or above on
+    /// those axes get simulated, each standing in for its reflections as well, which saves work
+    /// that grows with each mirrored axis.
+    ///
+    /// # Panics
+    ///
+    /// If there are fewer than `axes` axes.
+    #[must_use]
+    pub const fn mirrored(mut self, axes: usize) -> Self {
+        assert!(axes <= D, "can't mirror more axes than there are");
+        self.mirrored = axes;
+        self
+    }
+
+    /// How many cells are alive after the generations have run, from `alive` at the start. With
+    /// mirrored axes, `alive` only holds the cells at 0 or above on them, and the count includes
+    /// their reflections.
+    #[must_use]
+    pub fn run(&self, alive: FxHashSet<Cell<D>>) -> usize {
+        let dense = match self.backend {
+            Backend::Sparse => false,
+            Backend::Dense => true,
+            Backend::Auto => self.prefers_dense(&alive),
+        };
+        if dense {
+            self.run_dense(&alive)
+        } else {
+            self.run_sparse(alive)
+        }
+    }
+
+    fn prefers_dense(&self, alive: &FxHashSet<Cell<D>>) -> bool {
+        if alive.is_empty() {
+            return false;
+        }
+        let volume = self
+            .bounds(alive)
+            .lens
+            .iter()
+            .try_fold(1_usize, |v, &len| v.checked_mul(len));
+        volume.is_some_and(|v| v / (K * DENSE_RATIO) < alive.len())
+    }
+
+    /// Cells spread at most one step a generation and never past the edges of space, so
+    /// nothing outside `inner` ever comes alive. Around that there's room for the neighbours of
+    /// the outermost cells, and on mirrored axes for the neighbours of the reflections of cells
+    /// at 1.
+    fn bounds(&self, alive: &FxHashSet<Cell<D>>) -> Bounds<D> {
+        let generations = self.generations as i64;
+        let mirrored = D - self.mirrored..D;
+
+        let mut inner = [(0, 0); D];
+        let mut low = [0; D];
+        let mut lens = [0; D];
+        for i in 0..D {
+            let min = alive.iter().map(|c| i64::from(c[i])).min().unwrap();
+            let max = alive.iter().map(|c| i64::from(c[i])).max().unwrap();
+            inner[i] = if mirrored.contains(&i) {
+                (0, max + generations)
+            } else {
+                (min - generations, max + generations)
+            };
+            inner[i].0 = inner[i].0.max(Coord::MIN.into());
+            inner[i].1 = inner[i].1.min(Coord::MAX.into());
+            low[i] = inner[i].0 - 2;
+            lens[i] = (inner[i].1 - low[i] + 2) as usize;
+        }
+
+        Bounds { inner, low, lens }
+    }
+
+    fn run_sparse(&self, mut alive: FxHashSet<Cell<D>>) -> usize {
+        let capacity = alive.len() * K;
+        let mut counts: FxHashMap<Cell<D>, u32> =
+            FxHashMap::with_capacity_and_hasher(capacity, Default::default());
+        let mut next = FxHashSet::with_capacity_and_hasher(capacity, Default::default());
+        let mirrored = D - self.mirrored..D;
+
+        for _ in 0..self.generations {
+            counts.clear();
+            for cell in &alive {
+                'offsets: for offset in &Self::OFFSETS {
+                    let mut neighbor = *cell;
+                    let mut weight = 1;
+                    for i in 0..D {
+                        match cell[i].checked_add(offset[i]) {
+                            Some(c) => neighbor[i] = c,
+                            None => continue 'offsets,
+                        }
+                    }
+                    for i in mirrored.clone() {
+                        match (cell[i], neighbor[i]) {
+                            // The reflection is the one that neighbours cells below 0.
+                            (_, -1) => continue 'offsets,
+                            // Both this cell and its reflection neighbour cells at 0.
+                            (1, 0) => weight *= 2,
+                            _ => {}
+                        }
+                    }
+                    *counts.entry(neighbor).or_default() += weight;
+                }
+            }
+
+            next.clear();
+            next.extend(
+                counts
+                    .iter()
+                    .filter(|&(cell, &count)| self.rule.next(alive.contains(cell), count))
+                    .map(|(&cell, _)| cell),
+            );
+            if self.rule.next(true, 0) {
+                next.extend(alive.iter().filter(|cell| !counts.contains_key(*cell)));
+            }
+            std::mem::swap(&mut alive, &mut next);
+        }
+
+        alive
+            .iter()
+            .map(|cell| 1 << cell[mirrored.clone()].iter().filter(|&&c| c != 0).count())
+            .sum()
+    }
+
+    fn run_dense(&self, alive: &FxHashSet<Cell<D>>) -> usize {
+        if alive.is_empty() {
+            return 0;
+        }
+        let mirrored = D - self.mirrored..D;
+        let Bounds { inner, low, lens } = self.bounds(alive);
+
+        let mut strides = [1; D];
+        for i in (0..D.saturating_sub(1)).rev() {
+            strides[i] = strides[i + 1] * lens[i + 1];
+        }
+        let total = lens.iter().product();
+        let coord = |index: usize, i: usize| (index / strides[i] % lens[i]) as i64 + low[i];
+        let deltas = Self::OFFSETS.map(|o| {
+            (0..D)
+                .map(|i| o[i] as isize * strides[i] as isize)
+                .sum::<isize>()
+        });
+
+        let inside: Vec<bool> = (0..total)
+            .map(|index| (0..D).all(|i| (inner[i].0..=inner[i].1).contains(&coord(index, i))))
+            .collect();
+        let mut cells: Vec<usize> = alive
+            .iter()
+            .map(|c| {
+                (0..D)
+                    .map(|i| (i64::from(c[i]) - low[i]) as usize * strides[i])
+                    .sum()
+            })
+            .collect();
+        let mut is_alive = vec![false; total];
+        for &c in &cells {
+            is_alive[c] = true;
+        }
+        let mut counts = vec![0_u32; total];
+        let mut reflections = Vec::with_capacity(1 << self.mirrored);
+
+        for _ in 0..self.generations {
+            counts.fill(0);
+            for &c in &cells {
+                reflections.clear();
+                reflections.push(c);
+                for i in mirrored.clone().filter(|&i| coord(c, i) == 1) {
+                    for r in 0..reflections.len() {
+                        reflections.push(reflections[r] - 2 * strides[i]);
+                    }
+                }
+
+                for &r in &reflections {
+                    for d in &deltas {
+                        counts[r.wrapping_add_signed(*d)] += 1;
+                    }
+                }
+            }
+
+            let next: Vec<usize> = (0..total)
+                .filter(|&index| inside[index] && self.rule.next(is_alive[index], counts[index]))
+                .collect();
+            for &c in &cells {
+                is_alive[c] = false;
+            }
+            for &c in &next {
+                is_alive[c] = true;
+            }
+            cells = next;
+        }
+
+        cells
+            .iter()
+            .map(|&c| 1 << mirrored.clone().filter(|&i| coord(c, i) != 0).count())
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::{collection::vec, prelude::*};
+
+    #[test]
+    fn neighborhoods() {
+        let offsets = neighborhood::<2, 8>();
+        assert_eq!(
+            offsets,
+            [
+                [-1, -1],
+                [-1, 0],
+                [-1, 1],
+                [0, -1],
+                [0, 1],
+                [1, -1],
+                [1, 0],
+                [1, 1]
+            ]
+        );
+
+        let offsets: FxHashSet<_> = neighborhood::<4, 80>().iter().copied().collect();
+        assert_eq!(offsets.len(), 80);
+        assert!(!offsets.contains(&[0; 4]));
+    }
+
+    #[test]
+    fn life() {
+        let glider = [[0, 1], [1, 2], [2, 0], [2, 1], [2, 2]];
+        let blinker = [[0, 0], [0, 1], [0, 2]];
+
+        for backend in Backend::ALL {
+            let life = Automaton::<2, 8>::new(LIFE).backend(backend);
+            for generations in 0..20 {
+                assert_eq!(
+                    life.generations(generations)
+                        .run(glider.iter().copied().collect()),
+                    5
+                );
+            }
+
+            for generations in 0..5 {
+                assert_eq!(
+                    life.generations(generations)
+                        .run(blinker.iter().copied().collect()),
+                    3
+                );
+            }
+
+            // Seeds, where every cell dies, leaves the four cells beside the blinker's ends.
+            let seeds = Automaton::<2, 8>::new(Rule::new(&[2], &[])).backend(backend);
+            assert_eq!(
+                seeds.generations(1).run(blinker.iter().copied().collect()),
+                4
+            );
+            assert_eq!(seeds.run(FxHashSet::default()), 0);
+        }
+    }
+
+    #[test]
+    fn rules() {
+        assert!(LIFE.next(false, 3));
+        assert!(!LIFE.next(false, 2));
+        assert!(LIFE.next(true, 2));
+        assert!(!LIFE.next(true, 4));
+        assert!(!LIFE.next(true, 200));
+        assert!(Rule::new(&[1], &[0]).next(true, 0));
+    }
+
+    #[test]
+    fn mirroring() {
+        let start: FxHashSet<_> = [
+            [1, 0, 0, 0],
+            [2, 1, 0, 0],
+            [0, 2, 0, 0],
+            [1, 2, 0, 0],
+            [2, 2, 0, 0],
+        ]
+        .iter()
+        .copied()
+        .collect();
+        let space = Automaton::<4, 80>::new(LIFE);
+        for generations in 0..7 {
+            let space = space.generations(generations);
+            let expected = space.run(start.clone());
+            for backend in Backend::ALL {
+                for axes in 0..=2 {
+                    assert_eq!(
+                        space.backend(backend).mirrored(axes).run(start.clone()),
+                        expected,
+                        "{backend:?} with {axes} axes"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn auto() {
+        let pocket = Automaton::<4, 80>::new(LIFE).mirrored(2);
+        let crowded = (0..8)
+            .flat_map(|x| (0..8).map(move |y| [x, y, 0, 0]))
+            .filter(|&[x, y, ..]| (x + y) % 2 == 0)
+            .collect();
+        assert!(pocket.prefers_dense(&crowded));
+
+        let far_apart = [[0, 0, 0, 0], [1499, 1499, 0, 0]].iter().copied().collect();
+        assert!(!pocket.prefers_dense(&far_apart));
+
+        let corners = [[Coord::MIN, Coord::MIN, 0, 0], [Coord::MAX; 4]];
+        assert!(!pocket.prefers_dense(&corners.iter().copied().collect()));
+    }
+
+    #[test]
+    fn edges() {
+        // A blinker against the edge can't turn, so only the cells on this side of it are left.
+        let blinker: FxHashSet<_> = (0..3).map(|y| [Coord::MAX, y]).collect();
+        for backend in Backend::ALL {
+            let life = Automaton::<2, 8>::new(LIFE).backend(backend);
+            assert_eq!(life.generations(1).run(blinker.clone()), 2, "{backend:?}");
+            assert_eq!(life.generations(2).run(blinker.clone()), 0, "{backend:?}");
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn backends_agree(
+            cells in vec((-4..4 as Coord, -4..4 as Coord), 0..20),
+            birth in vec(1..27_u32, 0..4),
+            survive in vec(0..27_u32, 0..4),
+            generations in 0..5_usize,
+            mirrored in 0..=1_usize,
+        ) {
+            let space = Automaton::<3, 26>::new(Rule::new(&birth, &survive))
+                .generations(generations)
+                .mirrored(mirrored);
+            let start: FxHashSet<_> = cells.iter().map(|&(x, y)| [x, y, 0]).collect();
+
+            prop_assert_eq!(
+                space.backend(Backend::Dense).run(start.clone()),
+                space.backend(Backend::Sparse).run(start)
+            );
+        }
+    }
+}
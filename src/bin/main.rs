@@ -1,132 +1,1386 @@
-use aoc2020::{day_solver::DaySolver, days::*};
-use std::{cmp::PartialEq, fmt::Debug, time::Instant};
+use aoc::{
+    answers::{self, AnswerBook},
+    automaton::Backend,
+    day_solver::Stage,
+    error, gen,
+    heap::{self, Counting},
+    input::{HttpFetcher, InputProvider, Mapped},
+    reference,
+    registry::{self, Outcomes, Parts, Probe, SharedProbe, Solver},
+    scaffold::NewDay,
+};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use criterion::Criterion;
+use serde::Serialize;
+use std::{
+    collections::BTreeMap,
+    convert::TryFrom,
+    fs::File,
+    io::{self, BufReader, Seek, Write},
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{mpsc, Arc, Mutex},
+    time::{Duration, Instant},
+};
 
 #[cfg(debug_assertions)]
 #[global_allocator]
-static ALLOCATOR: dhat::DhatAlloc = dhat::DhatAlloc;
+static ALLOCATOR: Counting<dhat::DhatAlloc> = Counting(dhat::DhatAlloc);
 
-macro_rules! day {
-    ( $d:expr ) => {
-        day!($d => None, None);
-    };
+#[cfg(not(debug_assertions))]
+#[global_allocator]
+static ALLOCATOR: Counting<std::alloc::System> = Counting(std::alloc::System);
 
-    ( $d:expr, $o1:expr ) => {
-        day!($d => Some($o1), None);
-    };
+type Result<T, E = Box<dyn std::error::Error>> = std::result::Result<T, E>;
 
-    ( $d:expr, $o1:expr, $o2:expr ) => {
-        day!($d => Some($o1), Some($o2));
-    };
+#[derive(Parser)]
+#[command(
+    about = "Advent of Code solutions",
+    args_conflicts_with_subcommands = true
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    selection: Selection,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve days, printing answers and timings. This is the default.
+    Run(Selection),
+    /// Benchmark days with criterion.
+    Bench {
+        #[command(flatten)]
+        selection: Selection,
+
+        #[command(flatten)]
+        baselines: Baselines,
+    },
+    /// Solve days, only reporting whether the answers are the known ones.
+    Check(Selection),
+    /// List the days that have solutions.
+    List(Selection),
+    /// Start a new day from the template and register it.
+    NewDay(NewDayArgs),
+    /// Make up puzzle inputs from a seed, for scaling studies and fuzzing.
+    Gen(GenArgs),
+    /// Check days against their slow but simple reference solutions on generated inputs.
+    Diff(DiffArgs),
+}
+
+#[derive(Args)]
+struct NewDayArgs {
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Which event the day is from. Defaults to the latest one.
+    #[arg(long)]
+    year: Option<u16>,
+
+    /// Example input from the puzzle to seed the tests with.
+    #[arg(long)]
+    example: Option<String>,
+
+    /// What the example gives for part 1.
+    #[arg(long)]
+    part1: Option<String>,
+
+    /// What the example gives for part 2.
+    #[arg(long)]
+    part2: Option<String>,
+
+    /// Directory holding the `<year>/day<N>.txt` puzzle inputs.
+    #[arg(long, default_value = "input")]
+    input_dir: PathBuf,
+}
+
+#[derive(Args)]
+struct GenArgs {
+    /// Days to generate inputs for, such as `3`, `1-5` or `1,4,10-12`. Defaults to every day.
+    #[arg(value_delimiter = ',')]
+    days: Vec<DayRange>,
+
+    /// Which event the days are from. Defaults to the latest one.
+    #[arg(long)]
+    year: Option<u16>,
+
+    /// The same seed always gives the same input.
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// Roughly how many records, whatever those are for the day. Defaults to about as many as a
+    /// real input has.
+    #[arg(long)]
+    size: Option<usize>,
+
+    /// Generate this many inputs per day, from consecutive seeds.
+    #[arg(long, default_value_t = 1)]
+    count: u64,
+
+    /// Write `day<N>-<seed>.txt` files here instead of printing. Needed for more than one input.
+    #[arg(long, value_name = "DIR")]
+    out: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct DiffArgs {
+    /// Days to check, such as `3`, `1-5` or `1,4,10-12`. Defaults to every day.
+    #[arg(value_delimiter = ',')]
+    days: Vec<DayRange>,
+
+    /// Which event the days are from. Defaults to the latest one.
+    #[arg(long)]
+    year: Option<u16>,
+
+    /// The first seed to generate inputs from.
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// How many inputs to try per day, from consecutive seeds.
+    #[arg(long, default_value_t = 100)]
+    count: u64,
+
+    /// Roughly how many records each input has. Defaults to about as many as a real input has.
+    #[arg(long)]
+    size: Option<usize>,
+}
+
+#[derive(Args)]
+struct Selection {
+    /// Days to select, such as `3`, `1-5` or `1,4,10-12`. Defaults to every day.
+    #[arg(value_delimiter = ',')]
+    days: Vec<DayRange>,
+
+    /// Only solve this part.
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
 
-    ( $d:expr => $o1:expr, $o2:expr ) => {
-        paste::expr! {
-            solve::<_, [<day $d>]::[<Day $d>]>($d, $o1, $o2);
+    /// Read the puzzle input from this file instead. Needs exactly one day.
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Directory holding the `<year>/day<N>.txt` puzzle inputs.
+    #[arg(long, default_value = "input")]
+    input_dir: PathBuf,
+
+    /// Which events to solve, such as `2020` or `2020,2021`. Defaults to every event.
+    #[arg(long, value_delimiter = ',')]
+    year: Vec<u16>,
+
+    /// Save the answers as the verified ones instead of checking them.
+    #[arg(long)]
+    record: bool,
+
+    /// Report each stage's total, peak and count of heap allocations.
+    #[arg(long)]
+    profile_heap: bool,
+
+    /// Print one machine-readable record per part instead.
+    #[arg(long, value_enum)]
+    format: Option<Format>,
+
+    /// Solve this many days and parts at once. 0 uses one thread per core.
+    #[arg(short, long, default_value_t = 1)]
+    jobs: usize,
+
+    /// Give up on any stage still running after this many seconds. 0 waits forever.
+    #[arg(long, value_name = "SECONDS", default_value_t = 60.0)]
+    timeout: f64,
+
+    /// Map inputs into memory instead of reading them, so huge ones needn't fit on the heap.
+    #[arg(long)]
+    mmap: bool,
+
+    /// Parse the inputs of days that can a line at a time, so their text is never held all at once.
+    /// What the lines parse to is still kept. Their answers can't be recorded.
+    #[arg(long, conflicts_with_all = ["mmap", "record"])]
+    stream: bool,
+
+    /// How cellular automata like day 17's keep track of their cells: sparse, dense or auto.
+    #[arg(long, default_value = "auto")]
+    backend: Backend,
+}
+
+#[derive(Args, Default)]
+struct Baselines {
+    /// Save the results as this named baseline.
+    #[arg(long, value_name = "NAME", conflicts_with = "baseline")]
+    save_baseline: Option<String>,
+
+    /// Compare against this named baseline without overwriting it.
+    #[arg(long, value_name = "NAME")]
+    baseline: Option<String>,
+
+    /// Fail if any stage got slower than the baseline by more than this many percent.
+    #[arg(long, value_name = "PERCENT", default_value_t = 5.0)]
+    threshold: f64,
+}
+
+#[derive(Clone)]
+struct DayRange(RangeInclusive<u8>);
+
+impl FromStr for DayRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = |d: &str| match d.trim().parse() {
+            Ok(d @ 1..=25) => Ok(d),
+            _ => Err(format!("`{d}` is not a day from 1 to 25")),
+        };
+
+        let range = match s.split_once('-') {
+            Some((start, end)) => parse(start)?..=parse(end)?,
+            None => parse(s)?..=parse(s)?,
+        };
+
+        if range.is_empty() {
+            Err(format!("`{s}` is an empty range"))
+        } else {
+            Ok(Self(range))
         }
-    };
+    }
+}
+
+impl Selection {
+    /// Downloads missing inputs if there's a session to do it with.
+    fn provider(&self) -> InputProvider {
+        let provider = InputProvider::new(&self.input_dir);
+        match HttpFetcher::from_env() {
+            Some(fetcher) => provider.with_fetcher(fetcher),
+            None => provider,
+        }
+    }
+
+    fn read_input(
+        &self,
+        provider: &InputProvider,
+        year: u16,
+        day_number: u8,
+    ) -> io::Result<String> {
+        match &self.input {
+            Some(path) => std::fs::read_to_string(path).map_err(|e| {
+                io::Error::new(e.kind(), format!("{path}: {e}", path = path.display()))
+            }),
+            None => provider.get(year, day_number),
+        }
+    }
+
+    /// Reads, maps or opens the input for `solver`, depending on the flags. Benchmarks always
+    /// need all of it, so `whole` stops it being streamed.
+    fn load_input(
+        &self,
+        provider: &InputProvider,
+        solver: &Solver,
+        whole: bool,
+    ) -> io::Result<Input> {
+        let (year, day) = (solver.year(), solver.day());
+
+        if self.stream && solver.streams() && !whole {
+            let path = match &self.input {
+                Some(path) => path.clone(),
+                None => provider.locate(year, day)?,
+            };
+            let with_path = |e: io::Error| {
+                io::Error::new(e.kind(), format!("{path}: {e}", path = path.display()))
+            };
+            // Hashed up front, a chunk at a time, so the answers can still be checked.
+            let mut file = File::open(&path).map_err(with_path)?;
+            let hash = answers::reader_hash(BufReader::new(&file)).map_err(with_path)?;
+            file.rewind().map_err(with_path)?;
+            return Ok(Input::Lines(file, hash));
+        }
+
+        // Stages that time out are left running and still borrow it, so it can never be freed.
+        if self.mmap {
+            let mapped = match &self.input {
+                Some(path) => Mapped::open(path)?,
+                None => provider.map(year, day)?,
+            };
+            Ok(Input::Text(Box::leak(Box::new(mapped)).as_str()))
+        } else {
+            let input = self.read_input(provider, year, day)?;
+            Ok(Input::Text(Box::leak(input.into_boxed_str())))
+        }
+    }
+
+    fn includes_year(&self, year: u16) -> bool {
+        self.year.is_empty() || self.year.contains(&year)
+    }
+
+    fn includes(&self, day_number: u8) -> bool {
+        self.days.is_empty() || self.days.iter().any(|r| r.0.contains(&day_number))
+    }
+
+    fn parts(&self) -> Parts {
+        [1, 2].map(|p| self.part.is_none_or(|s| s == p))
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        (self.timeout > 0.0).then(|| Duration::from_secs_f64(self.timeout))
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Mode {
+    Run,
+    Bench,
+    Check,
+    List,
+}
+
+struct Config {
+    mode: Mode,
+    selection: Selection,
+    baselines: Baselines,
 }
 
 fn main() {
     #[cfg(debug_assertions)]
     let _dhat = dhat::Dhat::start_heap_profiling();
 
-    println!("AOC 2020");
-    day!(1, 964_875, 158_661_360);
-    day!(2, 515, 711);
-    day!(3, 292, 9_354_744_432);
-    day!(4, 247, 145);
-    day!(5, 864, 739);
-    day!(6, 6_590, 3_288);
-    day!(7, 287, 48_160);
-    day!(8, 1_528, 640);
-    day!(9, 29_221_323, 4_389_369);
-    day!(10, 2_240, 99_214_346_656_768);
-    day!(11, 2_319, 2_117);
-    day!(12, 820, 66_614);
-    day!(13, 3_464, 760_171_380_521_445);
-    day!(14, 18_630_548_206_046, 4_254_673_508_445);
-
-    #[cfg(not(debug_assertions))]
-    day!(15, 203, 9_007_186);
-
-    day!(16, 19_093, 5_311_123_569_883);
-    day!(17, 346, 1_632);
-    day!(18, 1_451_467_526_514, 224_973_686_321_527);
-    day!(19, 265, 394);
-    //day!(20, 12519494280967);
-}
-
-fn solve<O, S: for<'a> DaySolver<'a, Output = O>>(
-    day_number: u8,
-    part1_output: Option<O>,
-    part2_output: Option<O>,
-) {
-    let input = std::fs::read_to_string(format!("input/2020/day{}.txt", day_number)).unwrap();
-    let trimmed = input.trim();
-
-    let mut args = std::env::args();
-    if args.len() > 1 {
-        if args.any(|x| x == day_number.to_string() || x == "a") {
-            bench::<S>(day_number, trimmed);
+    let cli = Cli::parse();
+    let config = match cli.command {
+        None => Config {
+            mode: Mode::Run,
+            selection: cli.selection,
+            baselines: Baselines::default(),
+        },
+        Some(Command::Run(selection)) => Config {
+            mode: Mode::Run,
+            selection,
+            baselines: Baselines::default(),
+        },
+        Some(Command::Bench {
+            selection,
+            baselines,
+        }) => Config {
+            mode: Mode::Bench,
+            selection,
+            baselines,
+        },
+        Some(Command::Check(selection)) => Config {
+            mode: Mode::Check,
+            selection,
+            baselines: Baselines::default(),
+        },
+        Some(Command::List(selection)) => Config {
+            mode: Mode::List,
+            selection,
+            baselines: Baselines::default(),
+        },
+        Some(Command::NewDay(args)) => {
+            if let Err(e) = new_day(&args) {
+                eprintln!("{e}");
+                std::process::exit(1);
+            }
+            return;
+        }
+        Some(Command::Gen(args)) => {
+            if let Err(e) = generate(&args) {
+                eprintln!("{e}");
+                std::process::exit(1);
+            }
+            return;
+        }
+        Some(Command::Diff(args)) => match diff(&args) {
+            Ok(true) => return,
+            Ok(false) => std::process::exit(1),
+            Err(e) => {
+                eprintln!("{e}");
+                std::process::exit(1);
+            }
+        },
+    };
+
+    match run_all(&config) {
+        Ok(true) => {}
+        Ok(false) => std::process::exit(1),
+        Err(e) => {
+            eprintln!("\n{e}");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Returns whether every checked answer was correct.
+fn run_all(config: &Config) -> Result<bool> {
+    let selection = &config.selection;
+
+    let mut years: Vec<_> = registry::all().iter().map(Solver::year).collect();
+    years.dedup();
+    if let Some(y) = selection.year.iter().find(|y| !years.contains(y)) {
+        return Err(format!("no solutions for {y}").into());
+    }
+    years.retain(|&y| selection.includes_year(y));
+
+    if selection.input.is_some()
+        && !(matches!(&selection.days[..], [r] if r.0.start() == r.0.end()) && years.len() == 1)
+    {
+        return Err("--input needs exactly one day of one year".into());
+    }
+
+    // Machine-readable output owns stdout, so everything else goes to stderr.
+    let human = selection.format.is_none();
+    macro_rules! say {
+        ($($arg:tt)*) => {
+            if human {
+                println!($($arg)*);
+            } else {
+                eprintln!($($arg)*);
+            }
+        };
+    }
+
+    let provider = selection.provider();
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(selection.jobs)
+        .build()?;
+    let mut criterion = (config.mode == Mode::Bench).then(|| config.baselines.criterion());
+    let mut deltas = Vec::new();
+    let mut records = Vec::new();
+    let mut failures = Vec::new();
+    let mut wall_time = Duration::ZERO;
+    // Stages on different threads overlap, so this can be more than the wall-clock time.
+    let mut cpu_time = Duration::ZERO;
+
+    for (i, &year) in years.iter().enumerate() {
+        let solvers = registry::all().iter().filter(|s| s.year() == year);
+
+        let answers_path = provider.year_dir(year).join("answers.toml");
+        let mut book = AnswerBook::load(&answers_path).map_err(|e| {
+            format!(
+                "couldn't read {answers_path}: {e}",
+                answers_path = answers_path.display()
+            )
+        })?;
+
+        say!("{gap}AOC {year}", gap = if i > 0 { "\n" } else { "" });
+
+        if config.mode == Mode::List {
+            for s in solvers.filter(|s| selection.includes(s.day())) {
+                list(s, selection, &book);
+            }
+            continue;
+        }
+
+        let mut jobs = Vec::new();
+        for s in solvers {
+            if !runs(s, &selection.days) {
+                continue;
+            }
+            let s = &s.with_backend(selection.backend);
+
+            // Read up front rather than on the pool, so downloads happen one at a time.
+            let input = match selection.load_input(&provider, s, config.mode == Mode::Bench) {
+                Ok(input) => input,
+                Err(e) => {
+                    let error = error::Error::read(&e);
+                    records.extend(attempted(s, selection.parts()).map(|stage| {
+                        Record::new(year, s.day(), stage, &Measure::default(), selection)
+                            .failed(&error)
+                    }));
+                    failures.push(format!("{year} day {day} input: {e}", day = s.day()));
+                    continue;
+                }
+            };
+
+            if config.mode == Mode::Bench {
+                let Input::Text(input) = input else {
+                    unreachable!("benchmarks read the whole input");
+                };
+                let criterion = criterion.as_mut().unwrap();
+                bench(criterion, s, selection, input.trim())?;
+                if let Some(baseline) = &config.baselines.baseline {
+                    deltas.extend(compare(s, selection, baseline));
+                }
+                continue;
+            }
+
+            jobs.push((*s, input));
+        }
+
+        let started = Instant::now();
+        let mut recorded = false;
+        let solved = solve_in_order(&pool, jobs, selection.parts(), selection.timeout());
+        for Solved {
+            solver: s,
+            input,
+            hash,
+            outcomes,
+            probe,
+        } in solved
+        {
+            let s = &s;
+            let trimmed = input.map(str::trim);
+            cpu_time += probe.stages.iter().map(|s| s.cpu_time).sum::<Duration>();
+
+            let verbose = human && config.mode == Mode::Run;
+            if verbose {
+                println!("\nDay {day}:", day = s.day());
+            }
+
+            let outcomes = match outcomes {
+                Ok(outcomes) => outcomes,
+                Err(e) => {
+                    if verbose {
+                        println!("{e}");
+                    } else if human {
+                        println!(
+                            "Day {day:>2} {parse}: {e}",
+                            day = s.day(),
+                            parse = Stage::Parse,
+                            e = e.kind()
+                        );
+                    }
+                    records.extend(attempted(s, selection.parts()).map(|stage| {
+                        Record::new(year, s.day(), stage, &probe, selection).failed(&e)
+                    }));
+                    failures.push(format!("{year} {e}"));
+                    continue;
+                }
+            };
+
+            if verbose {
+                println!(
+                    "\tparser: {time:?}",
+                    time = probe.stages[Stage::Parse as usize].time
+                );
+                if selection.profile_heap {
+                    probe.stages[Stage::Parse as usize].print_heap();
+                }
+            }
+
+            if let (true, Some(trimmed)) = (selection.record, trimmed) {
+                let answers = outcomes.parts.clone().map(|o| o.and_then(Result::ok));
+                recorded |= book.record(s.day(), trimmed, &answers);
+            }
+
+            let expected = match trimmed {
+                Some(t) => book.get(t),
+                None => hash.as_deref().and_then(|h| book.get_by_hash(h)),
+            };
+            for (part, stage) in [(1, Stage::Part1), (2, Stage::Part2)] {
+                let record = Record::new(year, s.day(), stage, &probe, selection);
+                let answer = match &outcomes.parts[part as usize - 1] {
+                    None => continue,
+                    Some(Ok(answer)) => answer,
+                    Some(Err(e)) => {
+                        if verbose {
+                            println!("{stage}: {e}", e = e.kind());
+                        } else if human {
+                            println!("Day {day:>2} {stage}: {e}", day = s.day(), e = e.kind());
+                        }
+                        records.push(record.failed(e));
+                        failures.push(format!("{year} {e}"));
+                        continue;
+                    }
+                };
+
+                let expected = expected.and_then(|e| e.get(part));
+                let record = Record {
+                    status: match expected {
+                        Some(e) if e == answer => RecordStatus::Ok,
+                        Some(_) => RecordStatus::Wrong,
+                        None => RecordStatus::Unchecked,
+                    },
+                    answer: Some(answer.clone()),
+                    expected: expected.map(str::to_string),
+                    passed: expected.map(|e| e == answer),
+                    ..record
+                };
+
+                let status = match (&record.expected, record.passed) {
+                    (Some(_), Some(true)) => "ok".to_string(),
+                    (Some(expected), _) => {
+                        let status = format!("wrong answer, expected {expected} got {answer}");
+                        failures.push(format!("{year} day {day} {stage}: {status}", day = s.day()));
+                        status
+                    }
+                    (None, _) => "unchecked".to_string(),
+                };
+
+                if verbose {
+                    println!("{stage}: {answer}");
+                    println!(
+                        "\tsolver: {time:?}",
+                        time = probe.stages[stage as usize].time
+                    );
+                    if selection.profile_heap {
+                        probe.stages[stage as usize].print_heap();
+                    }
+                    if status != "ok" {
+                        println!("\t{status}");
+                    }
+                } else if human {
+                    println!("Day {day:>2} {stage}: {status}", day = s.day());
+                    if selection.profile_heap {
+                        probe.stages[stage as usize].print_heap();
+                    }
+                }
+
+                records.push(record);
+            }
+
+            if let Some(both) = &outcomes.both {
+                let stage = Stage::Both;
+                let record = Record::new(year, s.day(), stage, &probe, selection);
+                let answers = match both {
+                    Ok(answers) => answers,
+                    Err(e) => {
+                        if verbose {
+                            println!("{stage}: {e}", e = e.kind());
+                        } else if human {
+                            println!("Day {day:>2} {stage}: {e}", day = s.day(), e = e.kind());
+                        }
+                        records.push(record.failed(e));
+                        failures.push(format!("{year} {e}"));
+                        continue;
+                    }
+                };
+
+                let expected = match expected.map(|e| [e.get(1), e.get(2)]) {
+                    Some([Some(expected1), Some(expected2)]) => Some([expected1, expected2]),
+                    _ => None,
+                };
+                let passed = expected.map(|e| answers == &e);
+                records.push(Record {
+                    status: match passed {
+                        Some(true) => RecordStatus::Ok,
+                        Some(false) => RecordStatus::Wrong,
+                        None => RecordStatus::Unchecked,
+                    },
+                    answer: Some(answers.join(", ")),
+                    expected: expected.map(|e| e.join(", ")),
+                    passed,
+                    ..record
+                });
+
+                let status = match expected {
+                    Some([expected1, expected2]) => {
+                        if passed == Some(true) {
+                            "ok".to_string()
+                        } else {
+                            let status = format!("wrong answer, expected {expected1}, {expected2} got {answers}, {answers2}", answers = answers[0], answers2 = answers[1]);
+                            failures
+                                .push(format!("{year} day {day} {stage}: {status}", day = s.day()));
+                            status
+                        }
+                    }
+                    _ => "unchecked".to_string(),
+                };
+
+                if verbose {
+                    println!(
+                        "{stage}: {answers}, {answers2}",
+                        answers = answers[0],
+                        answers2 = answers[1]
+                    );
+                    println!(
+                        "\tsolver: {time:?}",
+                        time = probe.stages[stage as usize].time
+                    );
+                    if selection.profile_heap {
+                        probe.stages[stage as usize].print_heap();
+                    }
+                    if status != "ok" {
+                        println!("\t{status}");
+                    }
+                } else if human {
+                    println!("Day {day:>2} {stage}: {status}", day = s.day());
+                    if selection.profile_heap {
+                        probe.stages[stage as usize].print_heap();
+                    }
+                }
+            }
         }
+
+        wall_time += started.elapsed();
+
+        if recorded {
+            book.save(&answers_path).map_err(|e| {
+                format!(
+                    "couldn't write {answers_path}: {e}",
+                    answers_path = answers_path.display()
+                )
+            })?;
+            say!(
+                "\nRecorded answers in {answers_path}",
+                answers_path = answers_path.display()
+            );
+        }
+    }
+
+    if let Some(baseline) = &config.baselines.baseline {
+        println!("\nCompared to baseline {baseline}:");
+        for d in &deltas {
+            d.print(config.baselines.threshold);
+            if d.regressed(config.baselines.threshold) {
+                failures.push(format!(
+                    "{year} day {day} {stage} regressed by {change:+.1}%",
+                    year = d.year,
+                    day = d.day,
+                    stage = d.stage,
+                    change = d.change().unwrap_or_default()
+                ));
+            }
+        }
+    }
+
+    if let Some(format) = selection.format {
+        format.write(&records, selection.profile_heap)?;
+    }
+
+    if matches!(config.mode, Mode::Run | Mode::Check) {
+        if thread_cpu_time().is_some() {
+            say!(
+                "\nSolved in {wall_time:?} of wall-clock time and {cpu_time:?} of CPU time ({ratio:.1}x)",
+                ratio = cpu_time.as_secs_f64() / wall_time.as_secs_f64().max(f64::EPSILON)
+            );
+        } else {
+            say!("\nSolved in {wall_time:?} of wall-clock time");
+        }
+    }
+
+    if !failures.is_empty() {
+        say!("\n{count} failure(s):", count = failures.len());
+        for f in &failures {
+            say!("\t{f}");
+        }
+    }
+
+    Ok(failures.is_empty())
+}
+
+fn new_day(args: &NewDayArgs) -> Result<()> {
+    let year = args
+        .year
+        .or_else(|| registry::all().last().map(Solver::year))
+        .ok_or("no events yet, pass --year")?;
+
+    let touched = NewDay {
+        year,
+        day: args.day,
+        example: args.example.as_deref(),
+        answers: [args.part1.as_deref(), args.part2.as_deref()],
+    }
+    .generate(
+        &Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+        &args.input_dir,
+    )?;
+
+    for path in touched {
+        println!("wrote {path}", path = path.display());
+    }
+    println!(
+        "before pasting in its input, give day {day} a generator in src/gen and a reference in src/reference",
+        day = args.day
+    );
+    Ok(())
+}
+
+fn generate(args: &GenArgs) -> Result<()> {
+    let year = args
+        .year
+        .or_else(|| gen::all().last().map(gen::Generator::year))
+        .ok_or("no events yet, pass --year")?;
+
+    let generators: Vec<_> = gen::all()
+        .iter()
+        .filter(|g| {
+            g.year() == year
+                && (args.days.is_empty() || args.days.iter().any(|r| r.0.contains(&g.day())))
+        })
+        .collect();
+    if generators.is_empty() {
+        return Err(format!("no generators for {year}").into());
+    }
+
+    let seeds = args.seed..args.seed.saturating_add(args.count);
+    let Some(dir) = &args.out else {
+        let ([g], 1) = (&generators[..], args.count) else {
+            return Err("printing needs exactly one day and --count 1, or pass --out".into());
+        };
+        print!(
+            "{default_size}",
+            default_size = g.generate(args.seed, args.size.unwrap_or(g.default_size()))
+        );
+        return Ok(());
+    };
+
+    std::fs::create_dir_all(dir)?;
+    for g in generators {
+        for seed in seeds.clone() {
+            let path = dir.join(format!("day{day}-{seed}.txt", day = g.day()));
+            std::fs::write(
+                &path,
+                g.generate(seed, args.size.unwrap_or(g.default_size())),
+            )?;
+            println!("wrote {path}", path = path.display());
+        }
+    }
+    Ok(())
+}
+
+/// Whether `solver` gets run when `days` are asked for, which is every day when there are none.
+fn runs(solver: &Solver, days: &[DayRange]) -> bool {
+    if days.is_empty() {
+        // Slow days take ages without optimizations, so only run them when asked for.
+        !(cfg!(debug_assertions) && solver.is_slow())
     } else {
-        run::<S>(day_number, trimmed, part1_output, part2_output);
+        days.iter().any(|r| r.0.contains(&solver.day()))
+    }
+}
+
+/// Returns whether every day agreed with its reference.
+fn diff(args: &DiffArgs) -> Result<bool> {
+    let year = args
+        .year
+        .or_else(|| registry::all().last().map(Solver::year))
+        .ok_or("no events yet, pass --year")?;
+
+    let mut agreed = true;
+    for s in registry::all().iter().filter(|s| s.year() == year) {
+        if !runs(s, &args.days) {
+            continue;
+        }
+
+        let (Some(generator), Some(_)) = (gen::get(year, s.day()), reference::get(year, s.day()))
+        else {
+            println!(
+                "Day {day:>2}: no generator or reference to compare with",
+                day = s.day()
+            );
+            continue;
+        };
+        let size = args.size.unwrap_or(generator.default_size());
+        let seeds = args.seed..args.seed.saturating_add(args.count);
+        match reference::first_mismatch(s, seeds, size) {
+            None => println!(
+                "Day {day:>2}: agrees on {count} inputs",
+                day = s.day(),
+                count = args.count
+            ),
+            Some(m) => {
+                agreed = false;
+                println!(
+                    "Day {day:>2}: {m}\n{trim_end}",
+                    day = s.day(),
+                    trim_end = m.input.trim_end()
+                );
+            }
+        }
+    }
+    Ok(agreed)
+}
+
+fn list(solver: &Solver, selection: &Selection, book: &AnswerBook) {
+    // Listing shouldn't download anything, so only look at what's on disk.
+    let entry = selection
+        .read_input(
+            &InputProvider::new(&selection.input_dir),
+            solver.year(),
+            solver.day(),
+        )
+        .ok()
+        .and_then(|input| book.get(input.trim()).cloned())
+        .unwrap_or_default();
+    let known = |part| entry.get(part).unwrap_or("unknown");
+
+    print!(
+        "Day {day:>2}: part 1 {known}, part 2 {unsolved}",
+        day = solver.day(),
+        known = known(1),
+        unsolved = if solver.solves(2) {
+            known(2)
+        } else {
+            "unsolved"
+        }
+    );
+    if solver.is_slow() {
+        print!(" (slow)");
+    }
+    println!();
+}
+
+/// Where a day's input comes from.
+enum Input {
+    Text(&'static str),
+    /// Parsed a line at a time as it's read, with its [`answers::reader_hash`].
+    Lines(File, String),
+}
+
+struct Solved {
+    solver: Solver,
+    /// `None` if it was streamed.
+    input: Option<&'static str>,
+    /// The hash of a streamed input, to look its answers up by without the text.
+    hash: Option<String>,
+    outcomes: error::Result<Outcomes>,
+    probe: Measure,
+}
+
+/// Solves each day's `input` on `pool`, yielding them in the order given as soon as each one
+/// and those before it are done.
+fn solve_in_order(
+    pool: &rayon::ThreadPool,
+    jobs: Vec<(Solver, Input)>,
+    parts: Parts,
+    timeout: Option<Duration>,
+) -> impl Iterator<Item = Solved> {
+    let (tx, rx) = mpsc::channel();
+    let count = jobs.len();
+    for (i, (solver, input)) in jobs.into_iter().enumerate() {
+        let tx = tx.clone();
+        pool.spawn(move || {
+            let measure = Arc::new(Mutex::new(Measure::default()));
+            let probe: SharedProbe = measure.clone();
+            let (input, hash, outcomes) = match input {
+                Input::Text(text) => (
+                    Some(text),
+                    None,
+                    solver.run_isolated(text.trim(), parts, timeout, &probe),
+                ),
+                Input::Lines(file, hash) => (
+                    None,
+                    Some(hash),
+                    solver
+                        .run_reader(Box::new(BufReader::new(file)), parts, timeout, &probe)
+                        .expect("only days that stream get their input as lines"),
+                ),
+            };
+            let probe = std::mem::take(&mut *measure.lock().unwrap());
+            // Only fails if the receiver gave up, in which case nobody wants the result.
+            let _ = tx.send((
+                i,
+                Solved {
+                    solver,
+                    input,
+                    hash,
+                    outcomes,
+                    probe,
+                },
+            ));
+        });
+    }
+
+    let mut done = BTreeMap::new();
+    (0..count).map(move |i| loop {
+        if let Some(solved) = done.remove(&i) {
+            break solved;
+        }
+        let (j, solved) = rx.recv().unwrap();
+        done.insert(j, solved);
+    })
+}
+
+#[derive(Copy, Clone, Default)]
+struct StageStats {
+    time: Duration,
+    /// Time the stage's thread spent running, rather than waiting for a core. Zero where that
+    /// can't be measured.
+    cpu_time: Duration,
+    /// The most the stage had allocated at once, not counting what was live before it.
+    peak_heap: usize,
+    total_heap: usize,
+    allocations: usize,
+}
+
+impl StageStats {
+    fn print_heap(&self) {
+        println!(
+            "\theap: {total_heap} bytes total, {peak_heap} bytes peak, {allocations} allocations",
+            total_heap = self.total_heap,
+            peak_heap = self.peak_heap,
+            allocations = self.allocations
+        );
+    }
+}
+
+#[derive(Default)]
+struct Measure {
+    /// When each stage started, since the parts can overlap.
+    started: [Option<(Instant, Option<Duration>, heap::Stats)>; 4],
+    stages: [StageStats; 4],
+}
+
+impl Probe for Measure {
+    fn start(&mut self, stage: Stage) {
+        heap::reset_peak();
+        self.started[stage as usize] = Some((Instant::now(), thread_cpu_time(), heap::stats()));
+    }
+
+    fn finish(&mut self, stage: Stage) {
+        // Stages that timed out can still finish after the results were taken.
+        let Some((start_time, start_cpu, start_heap)) = self.started[stage as usize].take() else {
+            return;
+        };
+        let time = start_time.elapsed();
+        let cpu_time = start_cpu
+            .zip(thread_cpu_time())
+            .map_or(Duration::ZERO, |(start, end)| end - start);
+        let end_heap = heap::stats();
+        self.stages[stage as usize] = StageStats {
+            time,
+            cpu_time,
+            peak_heap: usize::try_from(end_heap.peak.saturating_sub(start_heap.current))
+                .unwrap_or(0),
+            total_heap: end_heap.total - start_heap.total,
+            allocations: end_heap.allocations - start_heap.allocations,
+        };
+    }
+}
+
+/// How much CPU time the calling thread has used. `None` where that can't be measured.
+fn thread_cpu_time() -> Option<Duration> {
+    #[cfg(unix)]
+    {
+        let mut now = libc::timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+        // Safety: `now` is a valid timespec for the call to fill in.
+        if unsafe { libc::clock_gettime(libc::CLOCK_THREAD_CPUTIME_ID, &mut now) } == 0 {
+            return Some(Duration::new(now.tv_sec as u64, now.tv_nsec as u32));
+        }
+    }
+    None
+}
+
+/// The stages after parsing that `solver` runs when `parts` are asked for.
+fn attempted(solver: &Solver, parts: Parts) -> impl Iterator<Item = Stage> + '_ {
+    let both = parts == [true, true] && solver.solves_both();
+    (1..=2)
+        .filter(move |&p| parts[p as usize - 1] && solver.solves(p))
+        .map(|p| if p == 1 { Stage::Part1 } else { Stage::Part2 })
+        .chain(both.then_some(Stage::Both))
+}
+
+/// How a part went, for the machine-readable formats.
+#[derive(Copy, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum RecordStatus {
+    Ok,
+    Wrong,
+    /// There's no known answer to compare with.
+    Unchecked,
+    Panicked,
+    TimedOut,
+    /// Anything else that kept the part from giving an answer, such as the input not parsing.
+    Error,
+}
+
+impl RecordStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Wrong => "wrong",
+            Self::Unchecked => "unchecked",
+            Self::Panicked => "panicked",
+            Self::TimedOut => "timed_out",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// Which part a [`Record`] is for, written as `both` for the pass that solves them together.
+#[derive(Copy, Clone)]
+enum RecordPart {
+    Part(u8),
+    Both,
+}
+
+impl Serialize for RecordPart {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            Self::Part(part) => s.serialize_u8(*part),
+            Self::Both => s.serialize_str("both"),
+        }
+    }
+}
+
+impl std::fmt::Display for RecordPart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Part(part) => write!(f, "{part}"),
+            Self::Both => f.write_str("both"),
+        }
+    }
+}
+
+/// One part of one day, or both at once for days that solve them in one pass. Every part that
+/// was attempted gets one, whether it gave an answer or not.
+#[derive(Serialize)]
+struct Record {
+    year: u16,
+    day: u8,
+    part: RecordPart,
+    status: RecordStatus,
+    /// `None` if the part failed.
+    answer: Option<String>,
+    expected: Option<String>,
+    passed: Option<bool>,
+    /// Why the part failed.
+    error: Option<String>,
+    parse_ns: u128,
+    solve_ns: u128,
+    peak_heap_bytes: usize,
+    #[serde(flatten)]
+    heap: Option<HeapProfile>,
+}
+
+impl Record {
+    /// A record of `stage` with the timings in `probe`, yet to be told how it went.
+    fn new(year: u16, day: u8, stage: Stage, probe: &Measure, selection: &Selection) -> Self {
+        let parse = &probe.stages[Stage::Parse as usize];
+        let part = match stage {
+            Stage::Part1 => RecordPart::Part(1),
+            Stage::Part2 => RecordPart::Part(2),
+            Stage::Both => RecordPart::Both,
+            Stage::Parse => unreachable!("parsing is recorded along with each part"),
+        };
+        let stage = &probe.stages[stage as usize];
+        Self {
+            year,
+            day,
+            part,
+            status: RecordStatus::Unchecked,
+            answer: None,
+            expected: None,
+            passed: None,
+            error: None,
+            parse_ns: parse.time.as_nanos(),
+            solve_ns: stage.time.as_nanos(),
+            peak_heap_bytes: stage.peak_heap,
+            heap: selection
+                .profile_heap
+                .then(|| HeapProfile::new(parse, stage)),
+        }
+    }
+
+    fn failed(self, error: &error::Error) -> Self {
+        Self {
+            status: match error.kind() {
+                error::ErrorKind::Panicked(_) => RecordStatus::Panicked,
+                error::ErrorKind::TimedOut(_) => RecordStatus::TimedOut,
+                _ => RecordStatus::Error,
+            },
+            error: Some(error.kind().to_string()),
+            ..self
+        }
     }
 }
 
-fn run<'a, S: DaySolver<'a>>(
-    day_number: u8,
-    input: &'a str,
-    part1_output: Option<S::Output>,
-    part2_output: Option<S::Output>,
-) {
-    let start_time = Instant::now();
-    let parsed = S::parse(input);
-    let end_time = Instant::now();
+/// The rest of the heap figures, only reported when asked for.
+#[derive(Serialize)]
+struct HeapProfile {
+    parse_total_heap_bytes: usize,
+    parse_peak_heap_bytes: usize,
+    parse_allocations: usize,
+    total_heap_bytes: usize,
+    allocations: usize,
+}
 
-    println!("\nDay {}:", day_number);
-    println!("\tparser: {:?}", (end_time - start_time));
+impl HeapProfile {
+    fn new(parse: &StageStats, part: &StageStats) -> Self {
+        Self {
+            parse_total_heap_bytes: parse.total_heap,
+            parse_peak_heap_bytes: parse.peak_heap,
+            parse_allocations: parse.allocations,
+            total_heap_bytes: part.total_heap,
+            allocations: part.allocations,
+        }
+    }
+}
 
-    run_part(parsed.clone(), 1, S::part1, part1_output);
-    run_part(parsed, 2, S::part2, part2_output);
+#[derive(Copy, Clone, ValueEnum)]
+enum Format {
+    Json,
+    Csv,
+    Markdown,
 }
 
-fn run_part<P, O: Debug + PartialEq>(
-    parsed: P,
-    part_number: u8,
-    part: impl Fn(P) -> O,
-    expected_output: Option<O>,
-) {
-    print!("Part {}: ", part_number);
+impl Format {
+    fn write(self, records: &[Record], profile_heap: bool) -> Result<()> {
+        let mut out = io::stdout().lock();
 
-    let start_time = Instant::now();
-    let result = part(parsed);
-    let end_time = Instant::now();
+        match self {
+            Self::Json => {
+                serde_json::to_writer_pretty(&mut out, records)?;
+                writeln!(out)?;
+            }
+            Self::Csv => {
+                write!(
+                    out,
+                    "year,day,part,status,answer,expected,passed,error,parse_ns,solve_ns,peak_heap_bytes"
+                )?;
+                if profile_heap {
+                    write!(out, ",parse_total_heap_bytes,parse_peak_heap_bytes,parse_allocations,total_heap_bytes,allocations")?;
+                }
+                writeln!(out)?;
 
-    println!("{:?}", result);
-    println!("\tsolver: {:?}", (end_time - start_time));
+                for r in records {
+                    write!(out, "{year},{day},{part},{status},{answer},{expected},{p},{error},{parse_ns},{solve_ns},{peak_heap_bytes}", year = r.year, day = r.day, part = r.part, status = r.status.as_str(), answer = csv_field(r.answer.as_deref().unwrap_or_default()), expected = csv_field(r.expected.as_deref().unwrap_or_default()), p = r.passed.map_or_else(String::new, |p| p.to_string()), error = csv_field(r.error.as_deref().unwrap_or_default()), parse_ns = r.parse_ns, solve_ns = r.solve_ns, peak_heap_bytes = r.peak_heap_bytes)?;
+                    if let Some(h) = &r.heap {
+                        write!(out, ",{parse_total_heap_bytes},{parse_peak_heap_bytes},{parse_allocations},{total_heap_bytes},{allocations}", parse_total_heap_bytes = h.parse_total_heap_bytes, parse_peak_heap_bytes = h.parse_peak_heap_bytes, parse_allocations = h.parse_allocations, total_heap_bytes = h.total_heap_bytes, allocations = h.allocations)?;
+                    }
+                    writeln!(out)?;
+                }
+            }
+            Self::Markdown => {
+                write!(
+                    out,
+                    "| Year | Day | Part | Answer | Expected | Result | Parse | Solve | Peak heap |"
+                )?;
+                if profile_heap {
+                    write!(
+                        out,
+                        " Parse heap | Parse peak | Parse allocs | Heap | Allocs |"
+                    )?;
+                }
+                writeln!(out)?;
+                write!(out, "|---:|---:|---:|---|---|---|---:|---:|---:|")?;
+                if profile_heap {
+                    write!(out, "---:|---:|---:|---:|---:|")?;
+                }
+                writeln!(out)?;
 
-    if let Some(expected) = expected_output {
-        assert_eq!(expected, result);
+                for r in records {
+                    write!(out, "| {year} | {day} | {part} | {error} | {unwrap_or} | {status} | {parse_ns:?} | {solve_ns:?} | {peak_heap_bytes} B |", year = r.year, day = r.day, part = r.part, error = r.answer
+                            .as_deref()
+                            .or(r.error.as_deref())
+                            .unwrap_or_default(), unwrap_or = r.expected.as_deref().unwrap_or("?"), status = r.status.as_str(), parse_ns = Duration::from_nanos(r.parse_ns as u64), solve_ns = Duration::from_nanos(r.solve_ns as u64), peak_heap_bytes = r.peak_heap_bytes)?;
+                    if let Some(h) = &r.heap {
+                        write!(out, " {parse_total_heap_bytes} B | {parse_peak_heap_bytes} B | {parse_allocations} | {total_heap_bytes} B | {allocations} |", parse_total_heap_bytes = h.parse_total_heap_bytes, parse_peak_heap_bytes = h.parse_peak_heap_bytes, parse_allocations = h.parse_allocations, total_heap_bytes = h.total_heap_bytes, allocations = h.allocations)?;
+                    }
+                    writeln!(out)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Quotes `s` if it would otherwise break the row, such as answers that are lists.
+fn csv_field(s: &str) -> std::borrow::Cow<'_, str> {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{escaped}\"", escaped = s.replace('"', "\"\"")).into()
     } else {
-        println!("Not checking input!");
+        s.into()
+    }
+}
+
+/// Where criterion keeps its results, fixed so the baselines can be read back.
+const CRITERION_DIR: &str = "target/criterion";
+
+impl Baselines {
+    fn criterion(&self) -> Criterion {
+        let mut criterion = Criterion::default()
+            .without_plots()
+            .output_directory(Path::new(CRITERION_DIR));
+        if let Some(name) = &self.save_baseline {
+            criterion = criterion.save_baseline(name.clone());
+        }
+        if let Some(name) = &self.baseline {
+            criterion = criterion.retain_baseline(name.clone());
+        }
+        criterion
     }
 }
 
-fn bench<'a, S: DaySolver<'a>>(day_number: u8, input: &'a str) {
-    let mut criterion = criterion::Criterion::default().without_plots();
-    let mut group = criterion.benchmark_group(format!("Day {}", day_number));
+fn bench(
+    criterion: &mut Criterion,
+    solver: &Solver,
+    selection: &Selection,
+    input: &str,
+) -> Result<()> {
+    let mut group = criterion.benchmark_group(group_name(solver));
+    solver.bench(input, selection.parts(), &mut group)?;
+    group.finish();
+    Ok(())
+}
+
+fn group_name(solver: &Solver) -> String {
+    format!("{year} Day {day}", year = solver.year(), day = solver.day())
+}
 
-    group.bench_with_input("parser", &input, |b, i| {
-        b.iter_with_large_drop(|| S::parse(i));
-    });
+struct Delta {
+    year: u16,
+    day: u8,
+    stage: Stage,
+    /// Mean times in nanoseconds, if criterion has them.
+    baseline: Option<f64>,
+    new: Option<f64>,
+}
 
-    let parsed = S::parse(input);
+impl Delta {
+    /// How much slower the new time is, in percent.
+    fn change(&self) -> Option<f64> {
+        Some((self.new? / self.baseline? - 1.0) * 100.0)
+    }
 
-    group.bench_with_input("part 1", &parsed, |b, i| {
-        b.iter_batched(|| i.clone(), S::part1, criterion::BatchSize::SmallInput)
-    });
+    fn regressed(&self, threshold: f64) -> bool {
+        self.change().is_some_and(|c| c > threshold)
+    }
+
+    fn print(&self, threshold: f64) {
+        let time = |t: Option<f64>| {
+            t.map_or_else(
+                || "?".to_string(),
+                |t| format!("{t:?}", t = Duration::from_nanos(t as u64)),
+            )
+        };
+        println!(
+            "{year} day {day:>2} {stage:<6}  {baseline:>12} -> {new:>12}  {change}{regressed}",
+            year = self.year,
+            day = self.day,
+            stage = self.stage,
+            baseline = time(self.baseline),
+            new = time(self.new),
+            change = self
+                .change()
+                .map_or_else(|| "no baseline".to_string(), |c| format!("{c:+.1}%")),
+            regressed = if self.regressed(threshold) {
+                "  regressed"
+            } else {
+                ""
+            }
+        );
+    }
+}
+
+/// Reads back what criterion just measured for `solver` against the saved `baseline`.
+fn compare(solver: &Solver, selection: &Selection, baseline: &str) -> Vec<Delta> {
+    let parts = selection.parts();
+    let group = Path::new(CRITERION_DIR).join(group_name(solver));
+
+    [
+        (Stage::Parse, "parser", true),
+        (Stage::Part1, "part 1", parts[0] && solver.solves(1)),
+        (Stage::Part2, "part 2", parts[1] && solver.solves(2)),
+        (
+            Stage::Both,
+            "both parts",
+            parts == [true, true] && solver.solves_both(),
+        ),
+    ]
+    .iter()
+    .filter(|&&(_, _, benched)| benched)
+    .map(|&(stage, id, _)| Delta {
+        year: solver.year(),
+        day: solver.day(),
+        stage,
+        baseline: mean_estimate(&group.join(id).join(baseline)),
+        new: mean_estimate(&group.join(id).join("new")),
+    })
+    .collect()
+}
 
-    group.bench_with_input("part 2", &parsed, |b, i| {
-        b.iter_batched(|| i.clone(), S::part2, criterion::BatchSize::SmallInput)
-    });
+fn mean_estimate(dir: &Path) -> Option<f64> {
+    let estimates = std::fs::read_to_string(dir.join("estimates.json")).ok()?;
+    let estimates: serde_json::Value = serde_json::from_str(&estimates).ok()?;
+    estimates["mean"]["point_estimate"].as_f64()
 }
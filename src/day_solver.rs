@@ -1,10 +1,77 @@
-use std::{cmp::PartialEq, fmt::Debug};
+use std::{
+    cmp::PartialEq,
+    fmt,
+    fmt::{Debug, Display},
+    io::BufRead,
+};
 
+use crate::error::Result;
+
+/// Parsing fails on input that isn't in the form the puzzle describes, and the parts on input
+/// that has no answer.
+#[allow(clippy::missing_errors_doc)]
 pub trait DaySolver<'a> {
-    type Parsed: Clone;
-    type Output: Debug + PartialEq;
+    /// The event the puzzle is from.
+    const YEAR: u16;
+    const DAY: u8;
+
+    /// `Send` and `Sync` so the parts can share it from different threads.
+    type Parsed: Clone + Send + Sync;
+    type Output: Debug + Display + PartialEq + Send;
+
+    /// Which parts would rather take the parsed input by value, because they change it. Those
+    /// implement [`Self::part1_owned`] or [`Self::part2_owned`], and have the borrowing version
+    /// clone the input into it. Callers only pass ownership when nothing else still needs it.
+    const CONSUMES: [bool; 2] = [false, false];
+
+    /// Set by days that override [`Self::solve_both`] to share work between the parts.
+    const SOLVES_BOTH: bool = false;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed>;
+    fn part1(data: &Self::Parsed) -> Result<Self::Output>;
+    fn part2(data: &Self::Parsed) -> Result<Self::Output>;
+
+    fn part1_owned(data: Self::Parsed) -> Result<Self::Output> {
+        Self::part1(&data)
+    }
+
+    fn part2_owned(data: Self::Parsed) -> Result<Self::Output> {
+        Self::part2(&data)
+    }
+
+    /// Both answers from a single pass, for days whose parts repeat each other's work.
+    fn solve_both(data: &Self::Parsed) -> Result<(Self::Output, Self::Output)> {
+        Ok((Self::part1(data)?, Self::part2(data)?))
+    }
+}
+
+/// Days whose input is one record per line, which can also be parsed straight from a reader.
+///
+/// Only one line of the text is held at a time, but whatever the lines parse to is still kept, so
+/// memory grows with the number of records unless the day folds them as it goes.
+#[allow(clippy::missing_errors_doc)]
+pub trait StreamingDaySolver<'a>: DaySolver<'a> {
+    /// Gives something the parts answer the same for as [`DaySolver::parse`] on the trimmed
+    /// contents of `reader`.
+    fn parse_reader(reader: impl BufRead) -> Result<Self::Parsed>;
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+    /// Both parts at once, through [`DaySolver::solve_both`].
+    Both,
+}
 
-    fn parse(input: &'a str) -> Self::Parsed;
-    fn part1(data: Self::Parsed) -> Self::Output;
-    fn part2(data: Self::Parsed) -> Self::Output;
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Parse => "parse",
+            Self::Part1 => "part 1",
+            Self::Part2 => "part 2",
+            Self::Both => "both parts",
+        })
+    }
 }
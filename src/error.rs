@@ -0,0 +1,212 @@
+use std::{any::Any, fmt, io, time::Duration};
+
+use crate::day_solver::Stage;
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// How many offending bytes are kept for the diagnostic.
+const MAX_FOUND: usize = 32;
+
+#[derive(Clone, Debug)]
+pub struct Error {
+    day: Option<u8>,
+    stage: Option<Stage>,
+    kind: ErrorKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The puzzle input doesn't match what the parser expects.
+    Parse {
+        line: usize,
+        column: usize,
+        found: Vec<u8>,
+        expected: &'static str,
+    },
+    /// The input parsed fine but has no answer.
+    NoSolution(&'static str),
+    /// The solver panicked with this message.
+    Panicked(String),
+    /// The solver was given up on after running this long.
+    TimedOut(Duration),
+    /// The puzzle input couldn't be read.
+    Read(String),
+}
+
+impl Error {
+    /// Builds a parse error pointing at `at`, which must be a subslice of `input`.
+    #[cold]
+    pub fn parse(input: impl AsRef<[u8]>, at: &[u8], expected: &'static str) -> Self {
+        let input = input.as_ref();
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&o| o <= input.len());
+        debug_assert!(offset.is_some(), "parse error outside of the input");
+        let offset = offset.unwrap_or(input.len());
+
+        let before = &input[..offset];
+        let line_start = before
+            .iter()
+            .rposition(|&c| c == b'\n')
+            .map_or(0, |p| p + 1);
+        let found = at.split(|&c| c == b'\n').next().unwrap_or_default();
+
+        Self::new(ErrorKind::Parse {
+            line: bytecount::count(before, b'\n') + 1,
+            column: offset - line_start + 1,
+            found: found[..found.len().min(MAX_FOUND)].to_vec(),
+            expected,
+        })
+    }
+
+    /// Builds a parse error for input that stopped early.
+    #[cold]
+    pub fn eof(input: impl AsRef<[u8]>, expected: &'static str) -> Self {
+        let input = input.as_ref();
+        Self::parse(input, &input[input.len()..], expected)
+    }
+
+    #[cold]
+    #[must_use]
+    pub fn read(e: &io::Error) -> Self {
+        Self::new(ErrorKind::Read(e.to_string()))
+    }
+
+    #[cold]
+    #[must_use]
+    pub fn no_solution(reason: &'static str) -> Self {
+        Self::new(ErrorKind::NoSolution(reason))
+    }
+
+    /// Builds an error from what a caught panic was called with.
+    #[cold]
+    pub fn panicked(payload: &(dyn Any + Send)) -> Self {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| (*s).to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown payload".to_string());
+        Self::new(ErrorKind::Panicked(message))
+    }
+
+    #[cold]
+    #[must_use]
+    pub fn timed_out(after: Duration) -> Self {
+        Self::new(ErrorKind::TimedOut(after))
+    }
+
+    /// Moves a parse error built against a single line to `line` of the whole input.
+    #[must_use]
+    pub(crate) fn on_line(mut self, line: usize) -> Self {
+        if let ErrorKind::Parse { line: l, .. } = &mut self.kind {
+            *l += line - 1;
+        }
+        self
+    }
+
+    fn new(kind: ErrorKind) -> Self {
+        Self {
+            day: None,
+            stage: None,
+            kind,
+        }
+    }
+
+    /// Records which day and stage the error came from.
+    #[must_use]
+    pub fn context(mut self, day: u8, stage: Stage) -> Self {
+        self.day = Some(day);
+        self.stage = Some(stage);
+        self
+    }
+
+    #[must_use]
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    #[must_use]
+    pub fn stage(&self) -> Option<Stage> {
+        self.stage
+    }
+
+    #[must_use]
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.day, self.stage) {
+            (Some(d), Some(s)) => write!(f, "day {d} {s}: ")?,
+            (Some(d), None) => write!(f, "day {d}: ")?,
+            (None, Some(s)) => write!(f, "{s}: ")?,
+            (None, None) => {}
+        }
+
+        self.kind.fmt(f)
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse {
+                line,
+                column,
+                found,
+                expected,
+            } => {
+                write!(
+                    f,
+                    "line {line}, column {column}: expected {expected}, found "
+                )?;
+                if found.is_empty() {
+                    write!(f, "nothing")
+                } else {
+                    write!(f, "{found:?}", found = String::from_utf8_lossy(found))
+                }
+            }
+            Self::NoSolution(reason) => write!(f, "no solution: {reason}"),
+            Self::Panicked(message) => write!(f, "panicked: {message}"),
+            Self::TimedOut(after) => write!(f, "timed out after {after:?}"),
+            Self::Read(message) => write!(f, "couldn't read the input: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_location() {
+        let input = "nop +0\nacc +1\njmp x4";
+        let e = Error::parse(input, &input.as_bytes()[18..], "a number");
+        assert_eq!(
+            e.kind(),
+            &ErrorKind::Parse {
+                line: 3,
+                column: 5,
+                found: b"x4".to_vec(),
+                expected: "a number",
+            }
+        );
+        assert_eq!(
+            e.context(8, Stage::Parse).to_string(),
+            "day 8 parse: line 3, column 5: expected a number, found \"x4\""
+        );
+    }
+
+    #[test]
+    fn parse_eof() {
+        let e = Error::eof("939\n", "bus ids");
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 1: expected bus ids, found nothing"
+        );
+    }
+}
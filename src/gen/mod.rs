@@ -0,0 +1,129 @@
+use std::sync::OnceLock;
+
+use rand::SeedableRng;
+
+use crate::day_solver::DaySolver;
+
+pub mod y2020;
+
+/// Seeded by [`generate`]. A fixed algorithm, so a seed gives the same input on every platform
+/// and version of `rand`.
+pub type Rng = rand_pcg::Pcg64;
+
+/// Makes up an input with roughly the given number of records, whatever a record is for the day.
+/// Inputs always suit the solver's assumptions, such as there being exactly one answer.
+pub type GenerateFn = fn(&mut Rng, usize) -> String;
+
+/// Makes up inputs for one day.
+pub struct Generator {
+    year: u16,
+    day: u8,
+    default_size: usize,
+    generate: GenerateFn,
+}
+
+impl Generator {
+    /// `default_size` is about the size of a real input.
+    pub fn new<S: DaySolver<'static>>(generate: GenerateFn, default_size: usize) -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            default_size,
+            generate,
+        }
+    }
+
+    #[must_use]
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    #[must_use]
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    #[must_use]
+    pub fn default_size(&self) -> usize {
+        self.default_size
+    }
+
+    /// The same seed and size always give the same input.
+    #[must_use]
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        (self.generate)(&mut Rng::seed_from_u64(seed), size)
+    }
+}
+
+const YEARS: &[fn() -> Vec<Generator>] = &[y2020::generators];
+
+/// Every generator, ordered by year then day.
+pub fn all() -> &'static [Generator] {
+    static GENERATORS: OnceLock<Vec<Generator>> = OnceLock::new();
+
+    GENERATORS.get_or_init(|| YEARS.iter().flat_map(|generators| generators()).collect())
+}
+
+/// Looks up the generator for `day` of `year`.
+#[must_use]
+pub fn get(year: u16, day: u8) -> Option<&'static Generator> {
+    all().iter().find(|g| g.year == year && g.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn every_one_has_a_day() {
+        for g in all() {
+            assert!(
+                registry::all()
+                    .iter()
+                    .any(|s| (s.year(), s.day()) == (g.year, g.day)),
+                "{year} day {day}",
+                year = g.year,
+                day = g.day
+            );
+        }
+    }
+
+    #[test]
+    fn reproducible() {
+        for g in all() {
+            assert_eq!(
+                g.generate(7, 20),
+                g.generate(7, 20),
+                "day {day}",
+                day = g.day
+            );
+        }
+    }
+
+    #[test]
+    fn generated_inputs_solve() {
+        for s in registry::testable() {
+            let Some(g) = get(s.year(), s.day()) else {
+                // A freshly scaffolded day has none, but one with a real input has no excuse.
+                assert!(
+                    registry::puzzle_input(s.year(), s.day()).is_none(),
+                    "{year} day {day} has a puzzle input but no generator",
+                    year = s.year(),
+                    day = s.day()
+                );
+                continue;
+            };
+            for (seed, size) in [(0, 1), (1, 10), (2, g.default_size)] {
+                let input = g.generate(seed, size);
+                let answers = s.run(input.trim(), [true, true], &mut ());
+                assert!(
+                    answers.is_ok(),
+                    "day {day} seed {seed} size {size}: {answers}\n{input}",
+                    day = s.day(),
+                    answers = answers.unwrap_err()
+                );
+            }
+        }
+    }
+}
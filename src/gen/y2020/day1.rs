@@ -0,0 +1,92 @@
+use rand::{seq::SliceRandom, Rng as _};
+use rustc_hash::FxHashMap;
+
+use crate::gen::Rng;
+
+const TARGET: u32 = 2020;
+
+/// Past this many, entries are made too big to be part of any sum, so checking stays cheap.
+const MAX_SMALL: usize = 500;
+
+/// `size` expenses, exactly one pair and one triple of which sum to 2020.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut sums = Sums::default();
+
+    loop {
+        let a = rng.gen_range(1..TARGET / 2);
+        if sums.add_all(&[(a, 0, 0), (TARGET - a, 1, 0)]) {
+            break;
+        }
+    }
+
+    loop {
+        let x = rng.gen_range(1..TARGET / 3);
+        let y = rng.gen_range(x + 1..(TARGET - x) / 2);
+        if sums.add_all(&[(x, 0, 0), (y, 0, 0), (TARGET - x - y, 0, 1)]) {
+            break;
+        }
+    }
+
+    let mut entries = sums.entries;
+    while entries.len() < size {
+        if entries.len() < MAX_SMALL {
+            let v = rng.gen_range(1..TARGET);
+            if sums_with(&entries, &sums.pairs, v) == (0, 0) {
+                add(&mut entries, &mut sums.pairs, v);
+                continue;
+            }
+        }
+        entries.push(rng.gen_range(TARGET..1_000_000));
+    }
+
+    entries.shuffle(rng);
+    entries.iter().map(|e| e.to_string() + "\n").collect()
+}
+
+/// The entries below the target, and how many pairs of them add up to each sum.
+#[derive(Default)]
+struct Sums {
+    entries: Vec<u32>,
+    pairs: FxHashMap<u32, usize>,
+}
+
+impl Sums {
+    /// Adds each value in turn if it makes exactly as many new pairs and triples summing to the
+    /// target as it's paired with. Adds none of them otherwise.
+    fn add_all(&mut self, values: &[(u32, usize, usize)]) -> bool {
+        for (i, &(v, pairs, triples)) in values.iter().enumerate() {
+            if sums_with(&self.entries, &self.pairs, v) != (pairs, triples) {
+                for &(v, ..) in &values[..i] {
+                    remove(&mut self.entries, &mut self.pairs, v);
+                }
+                return false;
+            }
+            add(&mut self.entries, &mut self.pairs, v);
+        }
+        true
+    }
+}
+
+/// How many new pairs and triples summing to the target adding `v` would make.
+fn sums_with(entries: &[u32], pairs: &FxHashMap<u32, usize>, v: u32) -> (usize, usize) {
+    let rest = TARGET - v;
+    (
+        entries.iter().filter(|&&e| e == rest).count(),
+        pairs.get(&rest).copied().unwrap_or_default(),
+    )
+}
+
+fn add(entries: &mut Vec<u32>, pairs: &mut FxHashMap<u32, usize>, v: u32) {
+    for &e in entries.iter() {
+        *pairs.entry(e + v).or_default() += 1;
+    }
+    entries.push(v);
+}
+
+fn remove(entries: &mut Vec<u32>, pairs: &mut FxHashMap<u32, usize>, v: u32) {
+    let i = entries.iter().rposition(|&e| e == v).unwrap();
+    entries.swap_remove(i);
+    for &e in entries.iter() {
+        *pairs.get_mut(&(e + v)).unwrap() -= 1;
+    }
+}
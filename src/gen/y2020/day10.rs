@@ -0,0 +1,43 @@
+use rand::seq::SliceRandom;
+
+use crate::gen::Rng;
+
+/// Past this many arrangements, only gaps of 3 get added, which don't make any more.
+const MAX_ARRANGEMENTS: u64 = 1 << 62;
+
+/// `size` adapters, mostly 1 or 3 jolts apart. Jolts are `u16`, so there are only so many.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    // The last few adapters' jolts and how many ways there are to reach each, starting from the
+    // outlet.
+    let mut recent = vec![(0_u16, 1_u64)];
+    let mut adapters = Vec::new();
+
+    while adapters.len() < size.max(1) {
+        let jolts = recent.last().unwrap().0;
+        let gap = *[1, 1, 1, 2, 3, 3].choose(rng).unwrap();
+        let Some(next) = jolts.checked_add(gap).filter(|&j| j <= u16::MAX - 6) else {
+            break;
+        };
+
+        let ways = |next: u16| -> u64 {
+            recent
+                .iter()
+                .filter(|&&(j, _)| next - j <= 3)
+                .map(|&(_, w)| w)
+                .sum()
+        };
+        let (next, ways) = match ways(next) {
+            w if w > MAX_ARRANGEMENTS => (jolts + 3, ways(jolts + 3)),
+            w => (next, w),
+        };
+
+        recent.push((next, ways));
+        if recent.len() > 3 {
+            recent.remove(0);
+        }
+        adapters.push(next);
+    }
+
+    adapters.shuffle(rng);
+    adapters.iter().map(|a| a.to_string() + "\n").collect()
+}
@@ -0,0 +1,113 @@
+use rand::{seq::SliceRandom, Rng as _};
+
+use crate::gen::Rng;
+
+const DIRECTIONS: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A `size` by `size` room of empty seats and floor.
+///
+/// Not every room settles down, some end up with seats flipping back and forth forever. So both
+/// parts' rules are run here, and some of the seats caught flipping are turned into floor until
+/// both do.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut room = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| if rng.gen_bool(0.85) { b'L' } else { b'.' })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    while let Some(flipping) = [(1, 4), (size, 5)]
+        .iter()
+        .find_map(|&(reach, crowd)| flipping(&room, reach, crowd))
+    {
+        for &(r, c) in flipping.choose_multiple(rng, flipping.len() / 5 + 1) {
+            room[r][c] = b'.';
+        }
+    }
+
+    room.iter()
+        .map(|row| String::from_utf8(row.clone()).unwrap() + "\n")
+        .collect()
+}
+
+/// Runs the rules where people look up to `reach` seats away and leave once `crowd` of the seats
+/// they see are taken. The seats that flip back and forth if it never settles, `None` if it does.
+fn flipping(room: &[Vec<u8>], reach: usize, crowd: usize) -> Option<Vec<(usize, usize)>> {
+    let size = room.len();
+    let seats = (0..size)
+        .flat_map(|r| (0..size).map(move |c| (r, c)))
+        .filter(|&(r, c)| room[r][c] == b'L')
+        .collect::<Vec<_>>();
+
+    let mut index = vec![vec![usize::MAX; size]; size];
+    for (i, &(r, c)) in seats.iter().enumerate() {
+        index[r][c] = i;
+    }
+    let seen = seats
+        .iter()
+        .map(|&(r, c)| {
+            DIRECTIONS
+                .iter()
+                .filter_map(|&(dr, dc)| {
+                    (1..=reach)
+                        .map(|d| {
+                            let r = r.checked_add_signed(dr * d as isize)?;
+                            let c = c.checked_add_signed(dc * d as isize)?;
+                            room.get(r)?.get(c)?;
+                            Some((r, c))
+                        })
+                        .take_while(Option::is_some)
+                        .flatten()
+                        .find_map(|(r, c)| (index[r][c] != usize::MAX).then(|| index[r][c]))
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let step = |taken: &[bool]| -> Vec<bool> {
+        seen.iter()
+            .zip(taken)
+            .map(|(seen, &taken_now)| {
+                let around = seen.iter().filter(|&&s| taken[s]).count();
+                if taken_now {
+                    around < crowd
+                } else {
+                    around == 0
+                }
+            })
+            .collect()
+    };
+
+    let mut before = vec![false; seats.len()];
+    let mut now = step(&before);
+    loop {
+        let next = step(&now);
+        if next == now {
+            return None;
+        }
+        if next == before {
+            return Some(
+                seats
+                    .iter()
+                    .zip(now.iter().zip(&next))
+                    .filter(|(_, (a, b))| a != b)
+                    .map(|(&seat, _)| seat)
+                    .collect(),
+            );
+        }
+        before = now;
+        now = next;
+    }
+}
@@ -0,0 +1,99 @@
+use std::{fmt::Write, mem::swap};
+
+use rand::{seq::SliceRandom, Rng as _};
+
+use crate::gen::Rng;
+
+type N = i32;
+
+/// How far from the start either ship can get, so nothing overflows.
+const LIMIT: N = 1 << 20;
+
+/// `size` navigation instructions. Both ships are followed along the way, and instructions that
+/// would take either too far are swapped for ones that don't.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut ships = Ships::default();
+    let mut out = String::new();
+
+    for _ in 0..size {
+        let (action, count) = loop {
+            let action = *b"NSEWLRF".choose(rng).unwrap();
+            let count = match action {
+                b'L' | b'R' => 90 * rng.gen_range(1..=3),
+                _ => rng.gen_range(1..=100),
+            };
+
+            let mut moved = ships;
+            moved.apply(action, count);
+            if moved.within(LIMIT) {
+                ships = moved;
+                break (action, count);
+            }
+        };
+        writeln!(out, "{action}{count}", action = action as char).unwrap();
+    }
+
+    out
+}
+
+/// Where the ships from both parts are.
+#[derive(Copy, Clone)]
+struct Ships {
+    ship1: (N, N),
+    facing: (N, N),
+    ship2: (N, N),
+    waypoint: (N, N),
+}
+
+impl Default for Ships {
+    fn default() -> Self {
+        Self {
+            ship1: (0, 0),
+            facing: (1, 0),
+            ship2: (0, 0),
+            waypoint: (10, 1),
+        }
+    }
+}
+
+impl Ships {
+    fn apply(&mut self, action: u8, count: N) {
+        let step = match action {
+            b'N' => (0, 1),
+            b'S' => (0, -1),
+            b'E' => (1, 0),
+            b'W' => (-1, 0),
+            b'F' => {
+                self.ship1.0 += self.facing.0 * count;
+                self.ship1.1 += self.facing.1 * count;
+                self.ship2.0 += self.waypoint.0 * count;
+                self.ship2.1 += self.waypoint.1 * count;
+                return;
+            }
+            _ => {
+                for _ in 0..count / 90 {
+                    for (x, y) in [&mut self.facing, &mut self.waypoint] {
+                        swap(x, y);
+                        if action == b'L' {
+                            *x *= -1;
+                        } else {
+                            *y *= -1;
+                        }
+                    }
+                }
+                return;
+            }
+        };
+
+        self.ship1.0 += step.0 * count;
+        self.ship1.1 += step.1 * count;
+        self.waypoint.0 += step.0 * count;
+        self.waypoint.1 += step.1 * count;
+    }
+
+    fn within(&self, limit: N) -> bool {
+        [self.ship1, self.ship2, self.waypoint]
+            .iter()
+            .all(|&(x, y)| x.abs() < limit && y.abs() < limit)
+    }
+}
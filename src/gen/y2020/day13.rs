@@ -0,0 +1,49 @@
+use rand::{seq::SliceRandom, Rng as _};
+
+use crate::gen::Rng;
+
+/// Part 2 multiplies the ids together as it goes, so they're kept below this.
+const MAX_PRODUCT: u64 = 1 << 50;
+
+/// A timetable `size` slots long. Bus ids are distinct primes, so they're coprime and part 2
+/// always has an answer, and the first slot always has a bus. Only one bus has the shortest wait.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut primes = primes(1000);
+    primes.shuffle(rng);
+
+    let mut slots = vec![None; size];
+    let mut product = 1;
+    for (i, &p) in primes.iter().enumerate() {
+        if product * p >= MAX_PRODUCT {
+            break;
+        }
+        let slot = if i == 0 { 0 } else { rng.gen_range(0..size) };
+        if slots[slot].is_none() {
+            slots[slot] = Some(p);
+            product *= p;
+        }
+    }
+
+    let buses: Vec<u64> = slots.iter().flatten().copied().collect();
+    let earliest = loop {
+        let earliest = rng.gen_range(100_000..=1_000_000);
+        let mut waits: Vec<_> = buses.iter().map(|id| (id - earliest % id) % id).collect();
+        waits.sort_unstable();
+        if waits.len() == 1 || waits[0] != waits[1] {
+            break earliest;
+        }
+    };
+
+    let ids = slots
+        .iter()
+        .map(|id| id.map_or_else(|| "x".to_string(), |id| id.to_string()))
+        .collect::<Vec<_>>();
+    format!("{earliest}\n{ids}\n", ids = ids.join(","))
+}
+
+fn primes(below: u64) -> Vec<u64> {
+    (2..below)
+        .filter(|&n| (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0))
+        .collect()
+}
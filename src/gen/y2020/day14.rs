@@ -0,0 +1,44 @@
+use std::fmt::Write;
+
+use rand::Rng as _;
+
+use crate::gen::Rng;
+
+/// Part 2 writes to every address a mask's floating bits could make, so masks have few of them.
+const MAX_FLOATING: usize = 9;
+
+/// `size` lines of masks, each followed by a few writes of 36 bit values to 16 bit addresses.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    let mut lines = 0;
+
+    while lines < size.max(1) {
+        let floating = rng.gen_range(0..=MAX_FLOATING);
+        let mut mask = (0..36)
+            .map(|_| if rng.gen() { b'1' } else { b'0' })
+            .collect::<Vec<_>>();
+        for _ in 0..floating {
+            mask[rng.gen_range(0..36)] = b'X';
+        }
+        writeln!(
+            out,
+            "mask = {mask}",
+            mask = String::from_utf8(mask).unwrap()
+        )
+        .unwrap();
+        lines += 1;
+
+        for _ in 0..rng.gen_range(1..=6) {
+            writeln!(
+                out,
+                "mem[{address}] = {value}",
+                address = rng.gen_range(0..1 << 16),
+                value = rng.gen_range(0..1_u64 << 36)
+            )
+            .unwrap();
+            lines += 1;
+        }
+    }
+
+    out
+}
@@ -0,0 +1,13 @@
+use rand::seq::index::sample;
+
+use crate::gen::Rng;
+
+/// `size` distinct starting numbers.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let numbers = sample(rng, size * 2, size)
+        .iter()
+        .map(|x| x.to_string())
+        .collect::<Vec<_>>();
+    numbers.join(",") + "\n"
+}
@@ -0,0 +1,100 @@
+use rand::{seq::SliceRandom, Rng as _};
+
+use crate::gen::Rng;
+
+const FIELDS: &[&str] = &[
+    "departure location",
+    "departure station",
+    "departure platform",
+    "departure track",
+    "departure date",
+    "departure time",
+    "arrival location",
+    "arrival station",
+    "arrival platform",
+    "arrival track",
+    "class",
+    "duration",
+    "price",
+    "route",
+    "row",
+    "seat",
+    "train",
+    "type",
+    "wagon",
+    "zone",
+];
+
+/// Part 1 adds up the invalid values in 16 bits.
+const MAX_ERROR_RATE: u32 = u16::MAX as u32;
+
+/// Rules, your ticket and `size` nearby tickets.
+///
+/// Values fall into one zone per field plus a last zone every rule accepts, with a number between
+/// each that none of them use. The `k`th field's rule accepts zones `k` onwards, and its position
+/// holds values from those zones only, at least once from zone `k`. So the `k`th field's rule
+/// fits exactly the positions of the fields from `k` onwards, and they can be matched one at a
+/// time from the last field back.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let fields = FIELDS.len();
+
+    let mut zones = Vec::new();
+    let mut start = rng.gen_range(25..50);
+    for _ in 0..=fields {
+        let end = start + rng.gen_range(5..40);
+        zones.push((start, end));
+        start = end + 2;
+    }
+    let invalid_below = zones[0].0;
+
+    let mut names = FIELDS.to_vec();
+    names.shuffle(rng);
+    let mut rules = (0..fields)
+        .map(|k| {
+            let split = rng.gen_range(k..fields);
+            format!(
+                "{name}: {min1}-{max1} or {min2}-{max2}\n",
+                name = names[k],
+                min1 = zones[k].0,
+                max1 = zones[split].1,
+                min2 = zones[split + 1].0,
+                max2 = zones[fields].1
+            )
+        })
+        .collect::<Vec<_>>();
+    rules.shuffle(rng);
+
+    let mut positions = (0..fields).collect::<Vec<_>>();
+    positions.shuffle(rng);
+    let ticket = |rng: &mut Rng, exact: bool| {
+        let mut ticket = vec![0; fields];
+        for (k, &p) in positions.iter().enumerate() {
+            let zone = if exact { k } else { rng.gen_range(k..=fields) };
+            ticket[p] = rng.gen_range(zones[zone].0..=zones[zone].1);
+        }
+        ticket
+    };
+
+    let mut nearby = vec![ticket(rng, true)];
+    let mut error_rate = 0;
+    while nearby.len() < size {
+        let mut t = ticket(rng, false);
+        let bad = rng.gen_range(0..invalid_below);
+        if rng.gen_bool(0.25) && error_rate + bad < MAX_ERROR_RATE {
+            t[rng.gen_range(0..fields)] = bad;
+            error_rate += bad;
+        }
+        nearby.push(t);
+    }
+    nearby.shuffle(rng);
+
+    let line = |t: &[u32]| t.iter().map(u32::to_string).collect::<Vec<_>>().join(",") + "\n";
+    let mut out = rules.concat();
+    out += "\nyour ticket:\n";
+    out += &line(&ticket(rng, false));
+    out += "\nnearby tickets:\n";
+    for t in &nearby {
+        out += &line(t);
+    }
+    out
+}
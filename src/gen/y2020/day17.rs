@@ -0,0 +1,19 @@
+use rand::Rng as _;
+
+use crate::gen::Rng;
+
+/// The reference simulates every cube, so much bigger slices take it too long to check.
+const MAX_SIZE: usize = 100;
+
+/// A `size` by `size` slice of active and inactive cubes.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.clamp(1, MAX_SIZE);
+    let mut out = String::new();
+
+    for _ in 0..size {
+        out.extend((0..size).map(|_| if rng.gen_bool(0.4) { '#' } else { '.' }));
+        out.push('\n');
+    }
+
+    out
+}
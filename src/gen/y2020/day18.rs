@@ -0,0 +1,42 @@
+use rand::Rng as _;
+
+use crate::gen::Rng;
+
+/// Operands per line, so no answer can overflow even summed over millions of lines.
+const MAX_OPERANDS: usize = 12;
+
+/// `size` expressions of single digits, additions, multiplications and brackets.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+
+    for _ in 0..size.max(1) {
+        let mut operands = rng.gen_range(2..=MAX_OPERANDS);
+        expression(rng, &mut out, &mut operands, 0);
+        out.push('\n');
+    }
+
+    out
+}
+
+/// Writes an expression using up at most `operands` digits, and at least one.
+fn expression(rng: &mut Rng, out: &mut String, operands: &mut usize, depth: usize) {
+    let terms = rng.gen_range(2..=4);
+
+    for i in 0..terms {
+        if i > 0 {
+            if *operands == 0 {
+                return;
+            }
+            *out += if rng.gen() { " + " } else { " * " };
+        }
+
+        if depth < 3 && *operands >= 2 && rng.gen_bool(0.3) {
+            out.push('(');
+            expression(rng, out, operands, depth + 1);
+            out.push(')');
+        } else {
+            out.push(char::from(b'0' + rng.gen_range(1..=9)));
+            *operands = operands.saturating_sub(1);
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use std::iter::repeat_n;
+
+use rand::{
+    seq::{index::sample, SliceRandom},
+    Rng as _,
+};
+
+use crate::gen::Rng;
+
+/// How many levels of rules there are, which is also one less than how long rules 42 and 31 are.
+const DEPTH: usize = 7;
+
+/// Rules, then `size` messages.
+///
+/// Rules come in pairs per level, matching strings of that level's length with an even and an
+/// odd number of `b`s respectively, built from the level below by adding one character to the
+/// front or back. Each level's rules match only one length and never the same string, so
+/// matching never needs to backtrack. Rules 42 and 31 are the top level's pair.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let fixed = [0, 8, 11, 42, 31];
+    let mut ids = sample(rng, 256, 256)
+        .into_iter()
+        .map(|id| id as u8)
+        .filter(|id| !fixed.contains(id));
+
+    let mut rules = vec![
+        "0: 8 11".to_string(),
+        "8: 42".to_string(),
+        "11: 42 31".to_string(),
+    ];
+    let (a, b) = (ids.next().unwrap(), ids.next().unwrap());
+    rules.push(format!("{a}: \"a\""));
+    rules.push(format!("{b}: \"b\""));
+
+    let (mut even, mut odd) = (a, b);
+    for level in 1..=DEPTH {
+        let (next_even, next_odd) = if level == DEPTH {
+            (42, 31)
+        } else {
+            (ids.next().unwrap(), ids.next().unwrap())
+        };
+
+        // An alias in between now and then, like the real rules have.
+        let (below_even, below_odd) = if rng.gen_bool(0.3) {
+            let (e, o) = (ids.next().unwrap(), ids.next().unwrap());
+            rules.push(format!("{e}: {even}"));
+            rules.push(format!("{o}: {odd}"));
+            (e, o)
+        } else {
+            (even, odd)
+        };
+
+        if rng.gen() {
+            rules.push(format!("{next_even}: {a} {below_even} | {b} {below_odd}"));
+            rules.push(format!("{next_odd}: {a} {below_odd} | {b} {below_even}"));
+        } else {
+            rules.push(format!("{next_even}: {below_even} {a} | {below_odd} {b}"));
+            rules.push(format!("{next_odd}: {below_odd} {a} | {below_even} {b}"));
+        }
+
+        even = next_even;
+        odd = next_odd;
+    }
+    rules.shuffle(rng);
+
+    let mut out = rules.join("\n");
+    out += "\n\n";
+    for _ in 0..size.max(1) {
+        let (forty_twos, thirty_ones) = match rng.gen_range(0..4) {
+            0 => (2, 1),
+            1 => {
+                let thirty_ones = rng.gen_range(1..6);
+                (
+                    rng.gen_range(thirty_ones + 1..=thirty_ones + 6),
+                    thirty_ones,
+                )
+            }
+            _ => (rng.gen_range(0..6), rng.gen_range(0..6)),
+        };
+
+        let mut message = Vec::new();
+        for parity in repeat_n(0, forty_twos).chain(repeat_n(1, thirty_ones)) {
+            message.extend(chunk(rng, parity));
+        }
+        if message.is_empty() || rng.gen_bool(0.1) {
+            message.push(if rng.gen() { b'a' } else { b'b' });
+        }
+
+        out += std::str::from_utf8(&message).unwrap();
+        out.push('\n');
+    }
+
+    out
+}
+
+/// A string rule 42 matches if `parity` is 0, or rule 31 if it's 1.
+fn chunk(rng: &mut Rng, parity: usize) -> Vec<u8> {
+    let mut chunk = (0..DEPTH)
+        .map(|_| if rng.gen() { b'a' } else { b'b' })
+        .collect::<Vec<_>>();
+    let bs = bytecount::count(&chunk, b'b');
+    chunk.push(if (bs + parity).is_multiple_of(2) {
+        b'a'
+    } else {
+        b'b'
+    });
+    chunk
+}
@@ -0,0 +1,24 @@
+use std::fmt::Write;
+
+use rand::Rng as _;
+
+use crate::gen::Rng;
+
+/// `size` password policies and passwords.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+
+    for _ in 0..size {
+        let len = rng.gen_range(1..=20);
+        // Fewer letters to pick from means the policy's letter turns up more than once.
+        let letters = rng.gen_range(2..=26);
+        let mut letter = || (b'a' + rng.gen_range(0..letters)) as char;
+        let password: String = (0..len).map(|_| letter()).collect();
+        let character = letter();
+        let num1 = rng.gen_range(1..=len);
+        let num2 = rng.gen_range(num1..=len);
+        writeln!(out, "{num1}-{num2} {character}: {password}").unwrap();
+    }
+
+    out
+}
@@ -0,0 +1,238 @@
+use std::fmt::Write;
+
+use rand::{
+    seq::{index::sample, SliceRandom},
+    Rng as _,
+};
+use rustc_hash::FxHashSet;
+
+use crate::gen::Rng;
+
+const MONSTER: [&[u8]; 3] = [
+    b"                  # ",
+    b"#    ##    ##    ###",
+    b" #  #  #  #  #  #   ",
+];
+
+/// Enough 10 pixel tiles across for a sea monster to fit.
+const MIN_SIZE: usize = 3;
+
+/// Tile ids are `u16`s.
+const MAX_SIZE: usize = 255;
+
+/// How many times to try for a border that's unlike any other before giving up on the width.
+const TRIES: usize = 100;
+
+/// A `size` by `size` image cut into tiles, each turned and flipped at random, with sea monsters
+/// in it.
+///
+/// Neighbouring tiles share their borders, and no two borders match otherwise, even reversed, so
+/// there's only one way to put the tiles back together. Tiles are 10 pixels wide like the real
+/// ones, unless there are too many to give them all different borders.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.clamp(MIN_SIZE, MAX_SIZE);
+    let (width, mut pixels) = (10..)
+        .find_map(|width| borders(rng, size, width).map(|pixels| (width, pixels)))
+        .unwrap();
+    image(rng, size, width, &mut pixels);
+
+    let ids = if size * size <= 9000 {
+        sample(rng, 9000, size * size)
+            .iter()
+            .map(|id| id + 1000)
+            .collect::<Vec<_>>()
+    } else {
+        sample(rng, usize::from(u16::MAX), size * size)
+            .iter()
+            .map(|id| id + 1)
+            .collect()
+    };
+
+    let mut tiles = Vec::new();
+    for r in 0..size {
+        for c in 0..size {
+            let mut tile = pixels[r * (width - 1)..][..width]
+                .iter()
+                .map(|row| row[c * (width - 1)..][..width].to_vec())
+                .collect::<Vec<_>>();
+            for _ in 0..rng.gen_range(0..4) {
+                tile = (0..width)
+                    .map(|i| (0..width).map(|j| tile[width - 1 - j][i]).collect())
+                    .collect();
+            }
+            if rng.gen() {
+                for row in &mut tile {
+                    row.reverse();
+                }
+            }
+            tiles.push((ids[r * size + c], tile));
+        }
+    }
+    tiles.shuffle(rng);
+
+    let mut out = String::new();
+    for (id, tile) in tiles {
+        writeln!(out, "Tile {id}:").unwrap();
+        for row in tile {
+            out += std::str::from_utf8(&row).unwrap();
+            out.push('\n');
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// The whole picture including every tile's borders, with only the borders filled in. Borders
+/// are shared with the neighbouring tile, so tiles are `width - 1` apart. `None` if there are too
+/// many borders to make them all different at this width.
+fn borders(rng: &mut Rng, size: usize, width: usize) -> Option<Vec<Vec<u8>>> {
+    let edge = size * (width - 1) + 1;
+    let mut pixels = vec![vec![b'.'; edge]; edge];
+    for r in (0..edge).step_by(width - 1) {
+        for c in (0..edge).step_by(width - 1) {
+            pixels[r][c] = pixel(rng, 0.5);
+        }
+    }
+
+    let mut used = FxHashSet::default();
+    for r in 0..=size {
+        for c in 0..=size {
+            let (r, c) = (r * (width - 1), c * (width - 1));
+            for vertical in [false, true] {
+                if (vertical && r + 1 == edge) || (!vertical && c + 1 == edge) {
+                    continue;
+                }
+                let at = |i: usize| if vertical { (r + i, c) } else { (r, c + i) };
+
+                let border = (0..TRIES).find_map(|_| {
+                    let mut border = (0..width)
+                        .map(|i| {
+                            let (y, x) = at(i);
+                            pixels[y][x]
+                        })
+                        .collect::<Vec<_>>();
+                    for b in &mut border[1..width - 1] {
+                        *b = pixel(rng, 0.5);
+                    }
+                    let mut reversed = border.clone();
+                    reversed.reverse();
+
+                    (border != reversed && !used.contains(&border)).then(|| {
+                        used.insert(reversed);
+                        border
+                    })
+                })?;
+                used.insert(border.clone());
+
+                for (i, b) in border.into_iter().enumerate() {
+                    let (y, x) = at(i);
+                    pixels[y][x] = b;
+                }
+            }
+        }
+    }
+
+    Some(pixels)
+}
+
+/// Fills in the inside of every tile, which together make up the image, and hides sea monsters
+/// in it.
+fn image(rng: &mut Rng, size: usize, width: usize, pixels: &mut [Vec<u8>]) {
+    let inner = width - 2;
+    let edge = size * inner;
+    let mut image = vec![vec![b'.'; edge]; edge];
+    for row in &mut image {
+        for p in row.iter_mut() {
+            *p = pixel(rng, 0.3);
+        }
+    }
+
+    let (height, length) = (MONSTER.len(), MONSTER[0].len());
+    let mut taken = vec![vec![false; edge]; edge];
+    if edge >= length {
+        for _ in 0..edge * edge / 200 {
+            let (y, x) = (
+                rng.gen_range(0..=edge - height),
+                rng.gen_range(0..=edge - length),
+            );
+            let cells = MONSTER
+                .iter()
+                .enumerate()
+                .flat_map(|(i, row)| {
+                    row.iter()
+                        .enumerate()
+                        .filter(|&(_, &p)| p == b'#')
+                        .map(move |(j, _)| (y + i, x + j))
+                })
+                .collect::<Vec<_>>();
+            if cells.iter().all(|&(y, x)| !taken[y][x]) {
+                for (y, x) in cells {
+                    taken[y][x] = true;
+                    image[y][x] = b'#';
+                }
+            }
+        }
+    }
+
+    scare_off(&mut image, &taken);
+
+    for (y, row) in image.iter().enumerate() {
+        for (x, &p) in row.iter().enumerate() {
+            pixels[y / inner * (width - 1) + 1 + y % inner]
+                [x / inner * (width - 1) + 1 + x % inner] = p;
+        }
+    }
+}
+
+/// Breaks up any sea monster that the noise made by chance, in any orientation, so the hidden
+/// ones are all there is to find and they're only found one way round.
+fn scare_off(image: &mut [Vec<u8>], taken: &[Vec<bool>]) {
+    let edge = image.len();
+    let (height, length) = (MONSTER.len(), MONSTER[0].len());
+    if edge < length {
+        return;
+    }
+
+    for orientation in 0..8 {
+        // Swaps rows with columns, then reverses rows, then columns, by the bits from the top.
+        let source = |(y, x): (usize, usize)| {
+            let (y, x) = if orientation & 4 == 0 { (y, x) } else { (x, y) };
+            (
+                if orientation & 2 == 0 {
+                    y
+                } else {
+                    edge - 1 - y
+                },
+                if orientation & 1 == 0 {
+                    x
+                } else {
+                    edge - 1 - x
+                },
+            )
+        };
+
+        for y in 0..=edge - height {
+            for x in 0..=edge - length {
+                let cells = MONSTER.iter().enumerate().flat_map(|(i, row)| {
+                    row.iter()
+                        .enumerate()
+                        .filter(|&(_, &p)| p == b'#')
+                        .map(move |(j, _)| source((y + i, x + j)))
+                });
+                if cells.clone().all(|(y, x)| image[y][x] == b'#') {
+                    if let Some((y, x)) = cells.clone().find(|&(y, x)| !taken[y][x]) {
+                        image[y][x] = b'.';
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn pixel(rng: &mut Rng, density: f64) -> u8 {
+    if rng.gen_bool(density) {
+        b'#'
+    } else {
+        b'.'
+    }
+}
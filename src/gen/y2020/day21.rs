@@ -0,0 +1,97 @@
+use std::fmt::Write;
+
+use rand::{
+    seq::{index::sample, SliceRandom},
+    Rng as _,
+};
+use rustc_hash::FxHashSet;
+
+use crate::gen::Rng;
+
+const ALLERGENS: [&str; 8] = [
+    "dairy",
+    "eggs",
+    "fish",
+    "nuts",
+    "peanuts",
+    "sesame",
+    "shellfish",
+    "soy",
+];
+
+/// At least `size` foods. Each allergen is in one ingredient, and foods keep being added until
+/// the lists narrow every allergen down to it, so no other ingredient is a candidate and
+/// eliminating pins them all down.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let count = rng.gen_range(1..=ALLERGENS.len());
+    let allergens = sample(rng, ALLERGENS.len(), count).into_vec();
+
+    let mut seen = FxHashSet::default();
+    let mut ingredients = Vec::new();
+    let count = allergens.len() + rng.gen_range(3..=100);
+    while ingredients.len() < count {
+        let name: String = (0..rng.gen_range(3..=8))
+            .map(|_| rng.gen_range('a'..='z'))
+            .collect();
+        if seen.insert(name.clone()) {
+            ingredients.push(name);
+        }
+    }
+
+    // The ingredient with `allergens[i]` is `ingredients[i]`, and the rest are safe.
+    let mut foods = Vec::new();
+    let mut candidates: Vec<Option<FxHashSet<usize>>> = vec![None; allergens.len()];
+    while foods.len() < size || !resolved(&candidates) {
+        let count = rng.gen_range(0..=allergens.len().min(3));
+        let listed = sample(rng, allergens.len(), count).into_vec();
+
+        let mut contents: Vec<usize> = listed.clone();
+        // Unlisted allergens can still be in there.
+        contents.extend((0..allergens.len()).filter(|a| !listed.contains(a) && rng.gen_bool(0.3)));
+        contents.extend((allergens.len()..ingredients.len()).filter(|_| rng.gen_bool(0.4)));
+        if contents.is_empty() {
+            contents.push(rng.gen_range(allergens.len()..ingredients.len()));
+        }
+
+        for &a in &listed {
+            let c = candidates[a].get_or_insert_with(|| contents.iter().copied().collect());
+            c.retain(|i| contents.contains(i));
+        }
+
+        contents.shuffle(rng);
+        let mut listed: Vec<_> = listed.iter().map(|&a| ALLERGENS[allergens[a]]).collect();
+        listed.shuffle(rng);
+        foods.push((contents, listed));
+    }
+
+    let mut out = String::new();
+    for (contents, listed) in foods {
+        let names: Vec<&str> = contents.iter().map(|&i| ingredients[i].as_str()).collect();
+        out += &names.join(" ");
+        if !listed.is_empty() {
+            write!(out, " (contains {listed})", listed = listed.join(", ")).unwrap();
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Whether every allergen is listed somewhere, has only dangerous candidates, and repeatedly
+/// taking the allergens down to one candidate pins them all down.
+fn resolved(candidates: &[Option<FxHashSet<usize>>]) -> bool {
+    let Some(mut candidates) = candidates.iter().cloned().collect::<Option<Vec<_>>>() else {
+        return false;
+    };
+    if candidates.iter().flatten().any(|&i| i >= candidates.len()) {
+        return false;
+    }
+
+    while let Some(a) = candidates.iter().position(|c| c.len() == 1) {
+        let i = *candidates[a].iter().next().unwrap();
+        for c in &mut candidates {
+            c.remove(&i);
+        }
+    }
+    candidates.iter().all(FxHashSet::is_empty)
+}
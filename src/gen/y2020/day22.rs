@@ -0,0 +1,55 @@
+use std::collections::VecDeque;
+
+use rand::seq::SliceRandom;
+use rustc_hash::FxHashSet;
+
+use crate::gen::Rng;
+
+/// Cards are `u8`s.
+const MAX_SIZE: usize = 127;
+
+/// `size` cards each from a shuffled deck numbered from 1. Deals where the game without
+/// recursion never ends are thrown away.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.clamp(1, MAX_SIZE);
+    let mut cards: Vec<usize> = (1..=size * 2).collect();
+    loop {
+        cards.shuffle(rng);
+        if ends(&cards[..size], &cards[size..]) {
+            break;
+        }
+    }
+
+    let deck = |cards: &[usize]| {
+        cards
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    format!(
+        "Player 1:\n{deck1}\n\nPlayer 2:\n{deck2}\n",
+        deck1 = deck(&cards[..size]),
+        deck2 = deck(&cards[size..])
+    )
+}
+
+fn ends(one: &[usize], two: &[usize]) -> bool {
+    let mut one: VecDeque<_> = one.iter().copied().collect();
+    let mut two: VecDeque<_> = two.iter().copied().collect();
+    let mut seen = FxHashSet::default();
+
+    while let (Some(&a), Some(&b)) = (one.front(), two.front()) {
+        if !seen.insert((one.clone(), two.clone())) {
+            return false;
+        }
+        one.pop_front();
+        two.pop_front();
+        if a > b {
+            one.extend([a, b]);
+        } else {
+            two.extend([b, a]);
+        }
+    }
+    true
+}
@@ -0,0 +1,14 @@
+use rand::seq::SliceRandom;
+
+use crate::gen::Rng;
+
+/// Labels are single digits, and a move picks up three cups besides the current one and the
+/// destination.
+const SIZES: std::ops::RangeInclusive<usize> = 5..=9;
+
+/// `size` cups labelled from 1 in a random order.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut cups: Vec<usize> = (1..=size.clamp(*SIZES.start(), *SIZES.end())).collect();
+    cups.shuffle(rng);
+    cups.iter().map(ToString::to_string).collect::<String>() + "\n"
+}
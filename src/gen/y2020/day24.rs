@@ -0,0 +1,22 @@
+use rand::{seq::SliceRandom, Rng as _};
+
+use crate::gen::Rng;
+
+const DIRECTIONS: [&str; 6] = ["e", "se", "sw", "w", "nw", "ne"];
+
+/// `size` lines of random directions. Some lines lead to the same tile, so it gets flipped back.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut lines = Vec::new();
+    while lines.len() < size.max(1) {
+        let line: String = (0..rng.gen_range(1..=20))
+            .map(|_| *DIRECTIONS.choose(rng).unwrap())
+            .collect();
+        if rng.gen_bool(0.05) {
+            lines.push(line.clone());
+        }
+        lines.push(line);
+    }
+    lines.truncate(size.max(1));
+    lines.shuffle(rng);
+    lines.join("\n") + "\n"
+}
@@ -0,0 +1,16 @@
+use rand::Rng as _;
+
+use crate::gen::Rng;
+
+const MODULUS: u64 = 20_201_227;
+
+/// Public keys from two random loop sizes up to `size`.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let most = (size as u64).clamp(1, MODULUS - 1);
+    let key = |loop_size| (0..loop_size).fold(1, |key, _| key * 7 % MODULUS);
+    format!(
+        "{card}\n{door}\n",
+        card = key(rng.gen_range(1..=most)),
+        door = key(rng.gen_range(1..=most))
+    )
+}
@@ -0,0 +1,15 @@
+use rand::Rng as _;
+
+use crate::gen::Rng;
+
+/// `size` rows of the usual 31 wide map.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+
+    for _ in 0..size.max(1) {
+        out.extend((0..31).map(|_| if rng.gen_bool(0.2) { '#' } else { '.' }));
+        out.push('\n');
+    }
+
+    out
+}
@@ -0,0 +1,100 @@
+use rand::{seq::SliceRandom, Rng as _};
+
+use crate::gen::Rng;
+
+const EYE_COLORS: [&str; 7] = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+
+/// `size` passports, some missing fields and some with fields that aren't valid.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut passports = Vec::new();
+
+    for _ in 0..size {
+        let mut fields = Vec::new();
+        for key in ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid", "cid"] {
+            if !rng.gen_bool(if key == "cid" { 0.5 } else { 0.9 }) {
+                continue;
+            }
+
+            let valid = rng.gen_bool(0.8);
+            let value = match key {
+                "byr" => year(rng, 1920, 2002, valid),
+                "iyr" => year(rng, 2010, 2020, valid),
+                "eyr" => year(rng, 2020, 2030, valid),
+                "hgt" => height(rng, valid),
+                "hcl" => hair_color(rng, valid),
+                "ecl" if valid => EYE_COLORS.choose(rng).unwrap().to_string(),
+                "ecl" => ["xry", "gmt", "lzr", "hzl2", "bl"]
+                    .choose(rng)
+                    .unwrap()
+                    .to_string(),
+                "pid" => {
+                    let digits = if valid {
+                        9
+                    } else {
+                        *[8, 10].choose(rng).unwrap()
+                    };
+                    (0..digits).map(|_| rng.gen_range('0'..='9')).collect()
+                }
+                _ => rng.gen_range(100..350).to_string(),
+            };
+            fields.push(format!("{key}:{value}"));
+        }
+
+        if fields.is_empty() {
+            continue;
+        }
+        fields.shuffle(rng);
+
+        let mut passport = String::new();
+        for (i, f) in fields.iter().enumerate() {
+            if i > 0 {
+                passport.push(if rng.gen_bool(0.3) { '\n' } else { ' ' });
+            }
+            passport += f;
+        }
+        passports.push(passport);
+    }
+
+    passports.join("\n\n")
+}
+
+fn year(rng: &mut Rng, min: u16, max: u16, valid: bool) -> String {
+    if valid {
+        rng.gen_range(min..=max).to_string()
+    } else if rng.gen_bool(0.5) {
+        rng.gen_range(min - 30..min).to_string()
+    } else {
+        rng.gen_range(max + 1..max + 30).to_string()
+    }
+}
+
+fn height(rng: &mut Rng, valid: bool) -> String {
+    let (unit, min, max) = *[("cm", 150, 193), ("in", 59, 76)].choose(rng).unwrap();
+    if valid {
+        format!("{value}{unit}", value = rng.gen_range(min..=max))
+    } else {
+        match rng.gen_range(0..3) {
+            0 => format!("{value}{unit}", value = rng.gen_range(max + 1..max + 50)),
+            1 => format!("{value}{unit}", value = rng.gen_range(10..min)),
+            _ => rng.gen_range(min..=max).to_string(),
+        }
+    }
+}
+
+fn hair_color(rng: &mut Rng, valid: bool) -> String {
+    const HEX: &[u8] = b"0123456789abcdef";
+    let mut color: String = (0..6).map(|_| *HEX.choose(rng).unwrap() as char).collect();
+
+    if valid {
+        format!("#{color}")
+    } else {
+        match rng.gen_range(0..3) {
+            0 => color,
+            1 => {
+                color.replace_range(..1, "z");
+                format!("#{color}")
+            }
+            _ => format!("#{color}", color = &color[..5]),
+        }
+    }
+}
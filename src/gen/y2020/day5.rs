@@ -0,0 +1,31 @@
+use rand::{seq::SliceRandom, Rng as _};
+
+use crate::gen::Rng;
+
+/// `size` boarding passes for a run of seats, all there but the one in the middle that's yours.
+/// There are only so many seats, so `size` is capped at 1021.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.clamp(2, 1021) as u16;
+    let first = rng.gen_range(1..=1023 - size - 1);
+    let mine = rng.gen_range(first + 1..first + size);
+
+    let mut seats: Vec<u16> = (first..=first + size).filter(|&s| s != mine).collect();
+    seats.shuffle(rng);
+
+    let mut out = String::new();
+    for s in seats {
+        out.extend(
+            (0..7)
+                .rev()
+                .map(|i| if s >> (i + 3) & 1 == 1 { 'B' } else { 'F' }),
+        );
+        out.extend(
+            (0..3)
+                .rev()
+                .map(|i| if s >> i & 1 == 1 { 'R' } else { 'L' }),
+        );
+        out.push('\n');
+    }
+
+    out
+}
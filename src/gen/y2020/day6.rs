@@ -0,0 +1,26 @@
+use rand::Rng as _;
+
+use crate::gen::Rng;
+
+/// `size` groups of up to 5 people, each answering yes to some questions.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut groups = Vec::new();
+
+    for _ in 0..size.max(1) {
+        let mut group = String::new();
+        // Questions the group is likely to agree on, so part 2 isn't always nothing.
+        let common = rng.gen::<u32>() & rng.gen::<u32>();
+        for _ in 0..rng.gen_range(1..=5) {
+            let answers = common | rng.gen::<u32>() & rng.gen::<u32>() | 1 << rng.gen_range(0..26);
+            group.extend(
+                (0..26)
+                    .filter(|i| answers >> i & 1 == 1)
+                    .map(|i| (b'a' + i) as char),
+            );
+            group.push('\n');
+        }
+        groups.push(group);
+    }
+
+    groups.join("\n")
+}
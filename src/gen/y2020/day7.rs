@@ -0,0 +1,75 @@
+use rand::{seq::SliceRandom, Rng as _};
+use rustc_hash::FxHashSet;
+
+use crate::gen::Rng;
+
+const ADJECTIVES: [&str; 20] = [
+    "bright", "clear", "dark", "dim", "dotted", "drab", "dull", "faded", "light", "mirrored",
+    "muted", "pale", "plaid", "posh", "shiny", "striped", "vibrant", "wavy", "dusky", "shady",
+];
+
+const COLORS: [&str; 20] = [
+    "aqua", "beige", "black", "blue", "bronze", "brown", "coral", "crimson", "cyan", "fuchsia",
+    "gold", "gray", "green", "indigo", "lime", "magenta", "olive", "orange", "plum", "red",
+];
+
+/// Levels of bags, each only holding bags from the level below, so the rules form a DAG.
+/// Shiny gold is in the middle and the tree under it stays small enough to walk.
+const LEVELS: usize = 8;
+
+/// Rules for `size` bags, including shiny gold.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut names = FxHashSet::default();
+    names.insert("shiny gold".to_string());
+    let mut bags = vec![("shiny gold".to_string(), LEVELS / 2)];
+
+    while bags.len() < size {
+        let name = if names.len() < ADJECTIVES.len() * COLORS.len() * 3 / 4 {
+            format!(
+                "{adjective} {color}",
+                adjective = ADJECTIVES.choose(rng).unwrap(),
+                color = COLORS.choose(rng).unwrap()
+            )
+        } else {
+            // Out of real sounding names.
+            let mut word = |len| -> String { (0..len).map(|_| rng.gen_range('a'..='z')).collect() };
+            format!("{adjective} {color}", adjective = word(6), color = word(5))
+        };
+
+        if names.insert(name.clone()) {
+            bags.push((name, rng.gen_range(0..LEVELS)));
+        }
+    }
+
+    let mut levels = vec![Vec::new(); LEVELS + 1];
+    for (name, level) in &bags {
+        levels[*level].push(name.as_str());
+    }
+
+    let mut rules: Vec<_> = bags
+        .iter()
+        .map(|(name, level)| {
+            let below = &levels[level + 1];
+            let count = rng.gen_range(0..=4.min(below.len()));
+            let contents: Vec<_> = below
+                .choose_multiple(rng, count)
+                .map(|inner| match rng.gen_range(1..=5) {
+                    1 => format!("1 {inner} bag"),
+                    n => format!("{n} {inner} bags"),
+                })
+                .collect();
+
+            if contents.is_empty() {
+                format!("{name} bags contain no other bags.\n")
+            } else {
+                format!(
+                    "{name} bags contain {contents}.\n",
+                    contents = contents.join(", ")
+                )
+            }
+        })
+        .collect();
+
+    rules.shuffle(rng);
+    rules.concat()
+}
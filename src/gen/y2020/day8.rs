@@ -0,0 +1,113 @@
+use std::{fmt::Write, ops::RangeInclusive};
+
+use rand::{seq::SliceRandom, Rng as _};
+
+use crate::gen::Rng;
+
+type N = i16;
+
+#[derive(Copy, Clone)]
+enum Instruction {
+    Acc(N),
+    Jmp(N),
+    Nop(N),
+}
+
+use Instruction::*;
+
+/// A program of `size` instructions that loops, and that exactly one swap of a `jmp` and `nop`
+/// makes terminate. Jumps here can land anywhere in the program and offsets are `i16`, so
+/// `size` is capped at 30000, though the solver runs longer programs fine.
+///
+/// Everything up to the corrupted `jmp` only leads back into itself, so that's where the
+/// program loops. Once it's a `nop` again it falls through to a path that only heads forward to
+/// the end, like the real inputs.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let len = size.clamp(2, 30_000);
+    let corrupted = rng.gen_range(1..len);
+    let mut program = vec![None; len];
+    let mut acc = 0;
+
+    let before = path(rng, 0, Some(corrupted), 0..=corrupted);
+    for w in before.windows(2) {
+        program[w[0]] = Some(step(rng, w[0], w[1], 0..=corrupted, &mut acc));
+    }
+    let back = before[rng.gen_range(0..before.len() - 1)];
+    program[corrupted] = Some(Jmp(back as N - corrupted as N));
+
+    if corrupted + 1 < len {
+        let mut after = path(rng, corrupted + 1, None, corrupted + 1..=len - 1);
+        after.sort_unstable();
+        after.push(len);
+        for w in after.windows(2) {
+            program[w[0]] = Some(step(rng, w[0], w[1], 0..=len, &mut acc));
+        }
+    }
+
+    let mut out = String::new();
+    for (i, instruction) in program.iter().enumerate() {
+        let targets = if i < corrupted {
+            0..=corrupted
+        } else {
+            0..=len
+        };
+        let instruction = instruction.unwrap_or_else(|| {
+            let offset = rng.gen_range(targets) as N - i as N;
+            *[Acc(rng.gen_range(-50..=50)), Jmp(offset), Nop(offset)]
+                .choose(rng)
+                .unwrap()
+        });
+
+        let (op, x) = match instruction {
+            Acc(x) => ("acc", x),
+            Jmp(x) => ("jmp", x),
+            Nop(x) => ("nop", x),
+        };
+        writeln!(out, "{op} {x:+}").unwrap();
+    }
+
+    out
+}
+
+/// Some of the instructions in `region` in a random order, starting with `first` and ending
+/// with `last` if given.
+fn path(
+    rng: &mut Rng,
+    first: usize,
+    last: Option<usize>,
+    region: RangeInclusive<usize>,
+) -> Vec<usize> {
+    let mut middle: Vec<_> = region
+        .filter(|&i| i != first && Some(i) != last && rng.gen_bool(0.5))
+        .collect();
+    middle.shuffle(rng);
+
+    let mut path = vec![first];
+    path.extend(middle);
+    path.extend(last);
+    path
+}
+
+/// An instruction at `from` that goes on to `to`. A `nop` only ever points into `nop_targets`,
+/// so that swapping it doesn't give another way out.
+fn step(
+    rng: &mut Rng,
+    from: usize,
+    to: usize,
+    nop_targets: RangeInclusive<usize>,
+    acc: &mut N,
+) -> Instruction {
+    if to != from + 1 || rng.gen_bool(0.3) {
+        Jmp(to as N - from as N)
+    } else if rng.gen_bool(0.6) {
+        let mut x = rng.gen_range(-50..=50);
+        // Keeps the accumulator from overflowing however long the program is.
+        if (*acc + x).abs() > 1000 {
+            x = -x;
+        }
+        *acc += x;
+        Acc(x)
+    } else {
+        Nop(rng.gen_range(nop_targets) as N - from as N)
+    }
+}
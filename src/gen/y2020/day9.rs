@@ -0,0 +1,58 @@
+use rand::{seq::SliceRandom, Rng as _};
+
+use crate::gen::Rng;
+
+const PREAMBLE: usize = 25;
+
+/// Past this, a number could overflow once it's summed up with others.
+const LIMIT: u64 = 1 << 56;
+
+/// `size` numbers after the preamble, one of which isn't the sum of two of the 25 before it but
+/// is the sum of a contiguous run. Each number has to outgrow the ones before it, so this stops
+/// short of `size` where they'd get too big, which is somewhere past 1000.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let len = PREAMBLE + size.max(2);
+    let invalid_at = rng.gen_range(PREAMBLE.midpoint(len)..len);
+    let mut invalid = false;
+
+    let mut nums: Vec<u64> = (0..PREAMBLE).map(|_| rng.gen_range(1..=100)).collect();
+    while nums.len() < len {
+        let next = valid(rng, &nums[nums.len() - PREAMBLE..]).filter(|&n| n < LIMIT);
+
+        if !invalid && (nums.len() == invalid_at || next.is_none()) {
+            nums.push(weakness(rng, &nums));
+            invalid = true;
+        } else if let Some(n) = next {
+            nums.push(n);
+        } else {
+            break;
+        }
+    }
+
+    nums.iter().map(|n| n.to_string() + "\n").collect()
+}
+
+/// A sum of two of the smaller numbers in `window`, to slow down how fast they grow.
+fn valid(rng: &mut Rng, window: &[u64]) -> Option<u64> {
+    let mut smallest = window.to_vec();
+    smallest.sort_unstable();
+    let pair: Vec<_> = smallest[..5].choose_multiple(rng, 2).collect();
+    pair[0].checked_add(*pair[1])
+}
+
+/// The sum of a contiguous run of `nums` that isn't the sum of two of the last 25.
+fn weakness(rng: &mut Rng, nums: &[u64]) -> u64 {
+    let window = &nums[nums.len() - PREAMBLE..];
+    loop {
+        let start = rng.gen_range(0..nums.len() - 2);
+        let end = rng.gen_range(start + 2..=(start + 17).min(nums.len()));
+        let sum: u64 = nums[start..end].iter().sum();
+
+        if !window
+            .iter()
+            .any(|&v| sum > v && window.contains(&(sum - v)))
+        {
+            break sum;
+        }
+    }
+}
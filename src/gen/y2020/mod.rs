@@ -0,0 +1,58 @@
+mod day1;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day2;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod day24;
+mod day25;
+mod day3;
+mod day4;
+mod day5;
+mod day6;
+mod day7;
+mod day8;
+mod day9;
+
+use super::Generator;
+use crate::years::y2020;
+
+pub fn generators() -> Vec<Generator> {
+    vec![
+        Generator::new::<y2020::day1::Day1>(day1::generate, 200),
+        Generator::new::<y2020::day2::Day2>(day2::generate, 1000),
+        Generator::new::<y2020::day3::Day3>(day3::generate, 323),
+        Generator::new::<y2020::day4::Day4>(day4::generate, 280),
+        Generator::new::<y2020::day5::Day5>(day5::generate, 789),
+        Generator::new::<y2020::day6::Day6>(day6::generate, 480),
+        Generator::new::<y2020::day7::Day7>(day7::generate, 594),
+        Generator::new::<y2020::day8::Day8>(day8::generate, 608),
+        Generator::new::<y2020::day9::Day9>(day9::generate, 975),
+        Generator::new::<y2020::day10::Day10>(day10::generate, 100),
+        Generator::new::<y2020::day11::Day11>(day11::generate, 92),
+        Generator::new::<y2020::day12::Day12>(day12::generate, 747),
+        Generator::new::<y2020::day13::Day13>(day13::generate, 70),
+        Generator::new::<y2020::day14::Day14>(day14::generate, 577),
+        Generator::new::<y2020::day15::Day15>(day15::generate, 7),
+        Generator::new::<y2020::day16::Day16>(day16::generate, 240),
+        Generator::new::<y2020::day17::Day17>(day17::generate, 8),
+        Generator::new::<y2020::day18::Day18>(day18::generate, 374),
+        Generator::new::<y2020::day19::Day19>(day19::generate, 470),
+        Generator::new::<y2020::day20::Day20>(day20::generate, 12),
+        Generator::new::<y2020::day21::Day21>(day21::generate, 40),
+        Generator::new::<y2020::day22::Day22>(day22::generate, 25),
+        Generator::new::<y2020::day23::Day23>(day23::generate, 9),
+        Generator::new::<y2020::day24::Day24>(day24::generate, 300),
+        Generator::new::<y2020::day25::Day25>(day25::generate, 10_000_000),
+    ]
+}
@@ -0,0 +1,118 @@
+use std::{
+    alloc::{GlobalAlloc, Layout},
+    cell::Cell,
+};
+
+/// Wraps an allocator to count what each thread allocates.
+pub struct Counting<A>(pub A);
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// Bytes currently allocated. Goes negative when freeing another thread's memory.
+    pub current: isize,
+    /// The most `current` has been since the last [`reset_peak`].
+    pub peak: isize,
+    /// Bytes allocated in total, counting the new size of every reallocation.
+    pub total: usize,
+    pub allocations: usize,
+}
+
+thread_local! {
+    static STATS: Cell<Stats> = const {
+        Cell::new(Stats {
+            current: 0,
+            peak: 0,
+            total: 0,
+            allocations: 0,
+        })
+    };
+}
+
+fn update(f: impl FnOnce(&mut Stats)) {
+    // Can only fail while the thread is being torn down, when nobody is looking anyway.
+    let _ = STATS.try_with(|s| {
+        let mut stats = s.get();
+        f(&mut stats);
+        stats.peak = stats.peak.max(stats.current);
+        s.set(stats);
+    });
+}
+
+/// The current thread's allocation counters.
+pub fn stats() -> Stats {
+    STATS.try_with(Cell::get).unwrap_or_default()
+}
+
+/// Starts tracking a new peak from what's allocated right now.
+pub fn reset_peak() {
+    update(|s| s.peak = s.current);
+}
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for Counting<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.0.alloc(layout);
+        if !ptr.is_null() {
+            update(|s| {
+                s.current += layout.size() as isize;
+                s.total += layout.size();
+                s.allocations += 1;
+            });
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.0.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            update(|s| {
+                s.current += layout.size() as isize;
+                s.total += layout.size();
+                s.allocations += 1;
+            });
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.0.dealloc(ptr, layout);
+        update(|s| s.current -= layout.size() as isize);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = self.0.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            update(|s| {
+                s.current += new_size as isize - layout.size() as isize;
+                s.total += new_size;
+                s.allocations += 1;
+            });
+        }
+        new_ptr
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::alloc::System;
+
+    #[test]
+    fn counts_this_thread() {
+        let heap = Counting(System);
+        let layout = Layout::from_size_align(64, 8).unwrap();
+        reset_peak();
+        let before = stats();
+
+        unsafe {
+            let ptr = heap.alloc(layout);
+            let ptr = heap.realloc(ptr, layout, 128);
+            heap.dealloc(ptr, Layout::from_size_align(128, 8).unwrap());
+        }
+
+        let after = stats();
+        assert_eq!(after.current, before.current);
+        assert_eq!(after.peak, before.current + 128);
+        assert_eq!(after.total - before.total, 64 + 128);
+        assert_eq!(after.allocations - before.allocations, 2);
+    }
+}
@@ -0,0 +1,332 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use memmap2::Mmap;
+
+/// Somewhere puzzle inputs can be downloaded from.
+pub trait Fetch: Send + Sync {
+    /// The input for `day` of `year`.
+    ///
+    /// # Errors
+    ///
+    /// If the download fails.
+    fn fetch(&self, year: u16, day: u8) -> io::Result<String>;
+}
+
+/// Downloads inputs from the Advent of Code website, or anything serving the same paths.
+pub struct HttpFetcher {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl HttpFetcher {
+    pub const BASE_URL: &'static str = "https://adventofcode.com";
+
+    /// Holds the session cookie of a logged in account, since inputs differ per account.
+    pub const SESSION_VAR: &'static str = "AOC_SESSION";
+
+    /// Overrides [`Self::BASE_URL`], for pointing at a stand-in server.
+    pub const URL_VAR: &'static str = "AOC_URL";
+
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent("github.com/smmalis37/aoc2020 input fetcher")
+                .build(),
+        }
+    }
+
+    /// Configures a fetcher from the environment, if there's a session to fetch with.
+    #[must_use]
+    pub fn from_env() -> Option<Self> {
+        let session = env::var(Self::SESSION_VAR).ok()?;
+        let base_url = env::var(Self::URL_VAR).unwrap_or_else(|_| Self::BASE_URL.to_string());
+        Some(Self::new(base_url, session.trim()))
+    }
+}
+
+impl Fetch for HttpFetcher {
+    fn fetch(&self, year: u16, day: u8) -> io::Result<String> {
+        let url = format!(
+            "{base_url}/{year}/day/{day}/input",
+            base_url = self.base_url
+        );
+
+        let response = self
+            .agent
+            .get(&url)
+            .set(
+                "Cookie",
+                &format!("session={session}", session = self.session),
+            )
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(code, _) => io::Error::other(format!(
+                    "{url} returned {code}, is {session_var} still valid?",
+                    session_var = Self::SESSION_VAR
+                )),
+                ureq::Error::Transport(t) => io::Error::other(format!("{url}: {t}")),
+            })?;
+
+        response.into_string()
+    }
+}
+
+/// An input file mapped into memory rather than read, so the OS can page a huge one in and out
+/// while it's parsed instead of all of it having to fit on the heap.
+pub struct Mapped(Mmap);
+
+impl Mapped {
+    /// It mustn't be changed while it's mapped.
+    ///
+    /// # Errors
+    ///
+    /// If the file can't be mapped or isn't UTF-8.
+    pub fn open(path: &Path) -> io::Result<Self> {
+        let with_path =
+            |e: io::Error| io::Error::new(e.kind(), format!("{path}: {e}", path = path.display()));
+
+        let file = fs::File::open(path).map_err(with_path)?;
+        // Nothing writes to inputs while they're being solved.
+        let map = unsafe { Mmap::map(&file) }.map_err(with_path)?;
+        std::str::from_utf8(&map)
+            .map_err(|e| with_path(io::Error::new(io::ErrorKind::InvalidData, e)))?;
+        Ok(Self(map))
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        // Checked when it was opened.
+        unsafe { std::str::from_utf8_unchecked(&self.0) }
+    }
+}
+
+/// Reads inputs from `<dir>/<year>/day<N>.txt`, downloading and saving any that are missing.
+pub struct InputProvider {
+    dir: PathBuf,
+    fetcher: Option<Box<dyn Fetch>>,
+}
+
+impl InputProvider {
+    /// A provider that only reads what's already on disk.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            fetcher: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_fetcher(mut self, fetcher: impl Fetch + 'static) -> Self {
+        self.fetcher = Some(Box::new(fetcher));
+        self
+    }
+
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    #[must_use]
+    pub fn year_dir(&self, year: u16) -> PathBuf {
+        self.dir.join(year.to_string())
+    }
+
+    #[must_use]
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.year_dir(year).join(format!("day{day}.txt"))
+    }
+
+    /// The untrimmed input for `day` of `year`.
+    ///
+    /// # Errors
+    ///
+    /// If the input can't be read, or is missing and can't be downloaded.
+    pub fn get(&self, year: u16, day: u8) -> io::Result<String> {
+        let path = self.path(year, day);
+
+        match fs::read_to_string(&path) {
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                let Some(fetcher) = &self.fetcher else {
+                    return Err(io::Error::new(
+                        e.kind(),
+                        format!(
+                            "{path} is missing, set {session_var} to download it",
+                            path = path.display(),
+                            session_var = HttpFetcher::SESSION_VAR
+                        ),
+                    ));
+                };
+
+                let input = fetcher.fetch(year, day)?;
+                fs::create_dir_all(self.year_dir(year))?;
+                fs::write(&path, &input)?;
+                Ok(input)
+            }
+            Err(e) => Err(io::Error::new(
+                e.kind(),
+                format!("{path}: {e}", path = path.display()),
+            )),
+            ok => ok,
+        }
+    }
+
+    /// Where the input for `day` of `year` is, downloading it first if it's missing.
+    ///
+    /// # Errors
+    ///
+    /// If it's missing and can't be downloaded.
+    pub fn locate(&self, year: u16, day: u8) -> io::Result<PathBuf> {
+        let path = self.path(year, day);
+        if !path.exists() {
+            self.get(year, day)?;
+        }
+        Ok(path)
+    }
+
+    /// Like [`Self::get`], but maps the input into memory instead of reading it.
+    ///
+    /// # Errors
+    ///
+    /// The same as [`Self::get`] and [`Mapped::open`].
+    pub fn map(&self, year: u16, day: u8) -> io::Result<Mapped> {
+        Mapped::open(&self.locate(year, day)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        thread,
+    };
+
+    /// Serves `/2020/day/1/input` to the `test` session, like the real site but on localhost.
+    struct MockServer {
+        url: String,
+        requests: Arc<AtomicUsize>,
+    }
+
+    impl MockServer {
+        fn start() -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!(
+                "http://{local_addr}",
+                local_addr = listener.local_addr().unwrap()
+            );
+            let requests = Arc::new(AtomicUsize::new(0));
+
+            let counter = Arc::clone(&requests);
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let mut stream = stream.unwrap();
+                    counter.fetch_add(1, Ordering::SeqCst);
+
+                    let mut request = Vec::new();
+                    for line in BufReader::new(&stream).lines() {
+                        let line = line.unwrap();
+                        if line.is_empty() {
+                            break;
+                        }
+                        request.push(line);
+                    }
+
+                    let authorized = request.iter().any(|l| l == "Cookie: session=test");
+                    let (status, body) = match request[0].as_str() {
+                        _ if !authorized => ("400 Bad Request", "Puzzle inputs differ by user."),
+                        "GET /2020/day/1/input HTTP/1.1" => ("200 OK", "1721\n979\n366\n"),
+                        _ => ("404 Not Found", "404 Not Found"),
+                    };
+
+                    write!(stream, "HTTP/1.1 {status}\r\nContent-Length: {body_len}\r\nConnection: close\r\n\r\n{body}", body_len = body.len())
+                    .unwrap();
+                }
+            });
+
+            Self { url, requests }
+        }
+
+        fn requests(&self) -> usize {
+            self.requests.load(Ordering::SeqCst)
+        }
+    }
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-input-{name}-{id}", id = std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn fetches_once_then_caches() {
+        let server = MockServer::start();
+        let dir = scratch_dir("cache");
+        let provider = InputProvider::new(&dir).with_fetcher(HttpFetcher::new(&server.url, "test"));
+
+        assert_eq!(provider.get(2020, 1).unwrap(), "1721\n979\n366\n");
+        assert_eq!(provider.get(2020, 1).unwrap(), "1721\n979\n366\n");
+        assert_eq!(server.requests(), 1);
+        assert_eq!(
+            fs::read_to_string(dir.join("2020/day1.txt")).unwrap(),
+            "1721\n979\n366\n"
+        );
+
+        assert!(provider.get(2020, 2).is_err());
+        assert!(!provider.path(2020, 2).exists());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn bad_session() {
+        let server = MockServer::start();
+        let dir = scratch_dir("session");
+        let provider =
+            InputProvider::new(&dir).with_fetcher(HttpFetcher::new(&server.url, "expired"));
+
+        let e = provider.get(2020, 1).unwrap_err();
+        assert!(e.to_string().contains("returned 400"), "{e}", e = e);
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn maps_inputs() {
+        let dir = scratch_dir("map");
+        fs::create_dir_all(dir.join("2020")).unwrap();
+        fs::write(dir.join("2020/day1.txt"), "1721\n979\n").unwrap();
+        fs::write(dir.join("2020/day2.txt"), "").unwrap();
+        fs::write(dir.join("2020/day3.txt"), b"\xff").unwrap();
+        let provider = InputProvider::new(&dir);
+
+        assert_eq!(provider.map(2020, 1).unwrap().as_str(), "1721\n979\n");
+        assert_eq!(provider.map(2020, 2).unwrap().as_str(), "");
+        assert_eq!(
+            provider.map(2020, 3).err().unwrap().kind(),
+            io::ErrorKind::InvalidData
+        );
+        assert!(provider.map(2020, 4).is_err());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn missing_without_fetcher() {
+        let provider = InputProvider::new(scratch_dir("offline"));
+        let e = provider.get(2020, 1).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::NotFound);
+        assert!(e.to_string().contains(HttpFetcher::SESSION_VAR));
+    }
+}
@@ -2,7 +2,6 @@
 #![allow(
     clippy::wildcard_imports,
     clippy::enum_glob_use,
-    clippy::match_on_vec_items,
     clippy::missing_const_for_fn,
     clippy::needless_range_loop,
     clippy::default_trait_access,
@@ -13,6 +12,15 @@
     clippy::cast_lossless
 )]
 
+pub mod answers;
+pub mod automaton;
 pub mod day_solver;
-pub mod days;
-pub(crate) mod util;
+pub mod error;
+pub mod gen;
+pub mod heap;
+pub mod input;
+pub mod reference;
+pub mod registry;
+pub mod scaffold;
+pub mod util;
+pub mod years;
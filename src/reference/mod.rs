@@ -0,0 +1,197 @@
+use std::{
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    sync::OnceLock,
+};
+
+use crate::{
+    day_solver::DaySolver,
+    error::{Error, Result},
+    gen,
+    registry::Solver,
+};
+
+pub mod y2020;
+
+/// Solves one part of a well-formed input the slow, obvious way, panicking on anything else.
+pub type PartFn = fn(&str) -> String;
+
+/// Straightforward solutions to check a day's solver against.
+pub struct Reference {
+    year: u16,
+    day: u8,
+    parts: [Option<PartFn>; 2],
+}
+
+impl Reference {
+    /// `part2` is `None` for days whose second part isn't solved yet.
+    pub fn new<S: DaySolver<'static>>(part1: PartFn, part2: Option<PartFn>) -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            parts: [Some(part1), part2],
+        }
+    }
+
+    #[must_use]
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    #[must_use]
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// The answer to `part`, which is 1 or 2, if there's a reference for it.
+    #[must_use]
+    pub fn solve(&self, input: &str, part: u8) -> Option<String> {
+        self.parts[usize::from(part - 1)].map(|solve| solve(input))
+    }
+}
+
+const YEARS: &[fn() -> Vec<Reference>] = &[y2020::references];
+
+/// Every reference, ordered by year then day.
+pub fn all() -> &'static [Reference] {
+    static REFERENCES: OnceLock<Vec<Reference>> = OnceLock::new();
+
+    REFERENCES.get_or_init(|| YEARS.iter().flat_map(|references| references()).collect())
+}
+
+/// Looks up the reference for `day` of `year`.
+#[must_use]
+pub fn get(year: u16, day: u8) -> Option<&'static Reference> {
+    all().iter().find(|r| r.year == year && r.day == day)
+}
+
+/// An input a solver and its reference disagree on.
+#[derive(Debug)]
+pub struct Mismatch {
+    pub day: u8,
+    pub part: u8,
+    pub seed: u64,
+    pub size: usize,
+    pub input: String,
+    /// What the reference got.
+    pub expected: String,
+    /// What the solver got, or how it failed.
+    pub found: Result<String>,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "day {day} part {part} with seed {seed} and size {size}: the reference got {expected} but the solver ", day = self.day, part = self.part, seed = self.seed, size = self.size, expected = self.expected)?;
+        match &self.found {
+            Ok(answer) => write!(f, "got {answer}"),
+            Err(e) => write!(f, "failed, {e}"),
+        }
+    }
+}
+
+/// Runs `solver` and its reference on an input generated from each of `seeds` in turn, and
+/// returns the first one they disagree on. Only parts both of them solve are compared.
+///
+/// # Panics
+///
+/// If the day has no generator or no reference.
+pub fn first_mismatch(
+    solver: &Solver,
+    seeds: impl IntoIterator<Item = u64>,
+    size: usize,
+) -> Option<Mismatch> {
+    let (year, day) = (solver.year(), solver.day());
+    let generator = gen::get(year, day).expect("every day has a generator");
+    let reference = get(year, day).expect("every day has a reference");
+
+    seeds.into_iter().find_map(|seed| {
+        let input = generator.generate(seed, size);
+        let trimmed = input.trim();
+        let answers = panic::catch_unwind(AssertUnwindSafe(|| {
+            solver.run(trimmed, [true, true], &mut ())
+        }))
+        .unwrap_or_else(|p| Err(Error::panicked(p.as_ref())));
+
+        (1..=2)
+            .filter(|&part| solver.solves(part))
+            .find_map(|part| {
+                let expected = reference.solve(trimmed, part)?;
+                let found = match &answers {
+                    Ok(answers) => Ok(answers[usize::from(part - 1)].clone().unwrap()),
+                    Err(e) => Err(e.clone()),
+                };
+                (found.as_ref().ok() != Some(&expected)).then_some((part, expected, found))
+            })
+            .map(|(part, expected, found)| Mismatch {
+                day,
+                part,
+                seed,
+                size,
+                input,
+                expected,
+                found,
+            })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::AnswerBook, registry};
+
+    #[test]
+    fn every_one_has_a_day() {
+        for r in all() {
+            assert!(
+                registry::all()
+                    .iter()
+                    .any(|s| (s.year(), s.day()) == (r.year, r.day)),
+                "{year} day {day}",
+                year = r.year,
+                day = r.day
+            );
+        }
+    }
+
+    #[test]
+    fn known_answers() {
+        for s in registry::testable() {
+            let (Some(r), Some((path, input))) = (
+                get(s.year(), s.day()),
+                registry::puzzle_input(s.year(), s.day()),
+            ) else {
+                continue;
+            };
+            let book =
+                AnswerBook::load(format!("input/{year}/answers.toml", year = r.year)).unwrap();
+            let entry = book.get(&input).expect(&path);
+
+            for (part, expected) in [(1, &entry.part1), (2, &entry.part2)] {
+                if let Some(answer) = r.solve(&input, part) {
+                    assert_eq!(Some(&answer), expected.as_ref(), "{path} part {part}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn solvers_agree() {
+        for s in registry::testable() {
+            let (Some(g), Some(_)) = (gen::get(s.year(), s.day()), get(s.year(), s.day())) else {
+                assert!(
+                    registry::puzzle_input(s.year(), s.day()).is_none(),
+                    "{year} day {day} has a puzzle input but no generator or reference",
+                    year = s.year(),
+                    day = s.day()
+                );
+                continue;
+            };
+            let size = g.default_size();
+            for (seeds, size) in [(0..20, 5), (0..2, size)] {
+                if let Some(m) = first_mismatch(s, seeds, size) {
+                    panic!("{m}\n{input}", input = m.input);
+                }
+            }
+        }
+    }
+}
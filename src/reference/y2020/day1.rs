@@ -0,0 +1,29 @@
+fn entries(input: &str) -> Vec<u64> {
+    input.lines().map(|l| l.parse().unwrap()).collect()
+}
+
+pub fn part1(input: &str) -> String {
+    let e = entries(input);
+    for i in 0..e.len() {
+        for j in i + 1..e.len() {
+            if e[i] + e[j] == 2020 {
+                return (e[i] * e[j]).to_string();
+            }
+        }
+    }
+    panic!("no pair sums to 2020")
+}
+
+pub fn part2(input: &str) -> String {
+    let e = entries(input);
+    for i in 0..e.len() {
+        for j in i + 1..e.len() {
+            for k in j + 1..e.len() {
+                if e[i] + e[j] + e[k] == 2020 {
+                    return (e[i] * e[j] * e[k]).to_string();
+                }
+            }
+        }
+    }
+    panic!("no triple sums to 2020")
+}
@@ -0,0 +1,35 @@
+use std::collections::HashMap;
+
+/// The outlet, the sorted adapters and the device.
+fn chain(input: &str) -> Vec<u64> {
+    let mut jolts: Vec<u64> = input.lines().map(|l| l.parse().unwrap()).collect();
+    jolts.push(0);
+    jolts.sort_unstable();
+    jolts.push(jolts.last().unwrap() + 3);
+    jolts
+}
+
+pub fn part1(input: &str) -> String {
+    let chain = chain(input);
+    let gaps = |gap| chain.windows(2).filter(|w| w[1] - w[0] == gap).count();
+    (gaps(1) * gaps(3)).to_string()
+}
+
+fn arrangements(chain: &[u64], from: usize, known: &mut HashMap<usize, u64>) -> u64 {
+    if from == chain.len() - 1 {
+        return 1;
+    }
+    if let Some(&ways) = known.get(&from) {
+        return ways;
+    }
+    let ways = (from + 1..chain.len())
+        .take_while(|&next| chain[next] - chain[from] <= 3)
+        .map(|next| arrangements(chain, next, known))
+        .sum();
+    known.insert(from, ways);
+    ways
+}
+
+pub fn part2(input: &str) -> String {
+    arrangements(&chain(input), 0, &mut HashMap::new()).to_string()
+}
@@ -0,0 +1,58 @@
+fn room(input: &str) -> Vec<Vec<char>> {
+    input.lines().map(|l| l.chars().collect()).collect()
+}
+
+/// Runs the rules until nothing changes, with people looking up to `reach` places away in each
+/// direction and leaving once `crowd` of the seats they see are taken.
+fn settle(mut room: Vec<Vec<char>>, reach: usize, crowd: usize) -> usize {
+    loop {
+        let mut next = room.clone();
+        for r in 0..room.len() {
+            for c in 0..room[r].len() {
+                let mut taken = 0;
+                for dr in -1..=1_i64 {
+                    for dc in -1..=1_i64 {
+                        if (dr, dc) == (0, 0) {
+                            continue;
+                        }
+                        for d in 1..=reach as i64 {
+                            let (y, x) = (r as i64 + dr * d, c as i64 + dc * d);
+                            if y < 0 || x < 0 {
+                                break;
+                            }
+                            let Some(&seen) =
+                                room.get(y as usize).and_then(|row| row.get(x as usize))
+                            else {
+                                break;
+                            };
+                            if seen != '.' {
+                                taken += usize::from(seen == '#');
+                                break;
+                            }
+                        }
+                    }
+                }
+                next[r][c] = match room[r][c] {
+                    'L' if taken == 0 => '#',
+                    '#' if taken >= crowd => 'L',
+                    seat => seat,
+                };
+            }
+        }
+
+        if next == room {
+            return room.iter().flatten().filter(|&&s| s == '#').count();
+        }
+        room = next;
+    }
+}
+
+pub fn part1(input: &str) -> String {
+    settle(room(input), 1, 4).to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    let room = room(input);
+    let reach = room.len().max(room[0].len());
+    settle(room, reach, 5).to_string()
+}
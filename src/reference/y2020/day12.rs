@@ -0,0 +1,45 @@
+fn instructions(input: &str) -> Vec<(char, i64)> {
+    input
+        .lines()
+        .map(|l| (l.chars().next().unwrap(), l[1..].parse().unwrap()))
+        .collect()
+}
+
+/// Where the ship ends up. Without a waypoint, `heading` is where it's facing and compass
+/// moves shift the ship. With one, `heading` is the waypoint and compass moves shift that.
+fn navigate(input: &str, mut heading: (i64, i64), waypoint: bool) -> i64 {
+    let mut ship = (0, 0);
+    for (action, value) in instructions(input) {
+        let step = match action {
+            'N' => (0, 1),
+            'S' => (0, -1),
+            'E' => (1, 0),
+            'W' => (-1, 0),
+            'L' | 'R' => {
+                for _ in 0..value / 90 {
+                    heading = if action == 'L' {
+                        (-heading.1, heading.0)
+                    } else {
+                        (heading.1, -heading.0)
+                    };
+                }
+                continue;
+            }
+            _ => {
+                ship = (ship.0 + heading.0 * value, ship.1 + heading.1 * value);
+                continue;
+            }
+        };
+        let moved = if waypoint { &mut heading } else { &mut ship };
+        *moved = (moved.0 + step.0 * value, moved.1 + step.1 * value);
+    }
+    ship.0.abs() + ship.1.abs()
+}
+
+pub fn part1(input: &str) -> String {
+    navigate(input, (1, 0), false).to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    navigate(input, (10, 1), true).to_string()
+}
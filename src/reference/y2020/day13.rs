@@ -0,0 +1,49 @@
+/// The earliest timestamp and each bus with its offset in the list.
+fn notes(input: &str) -> (u64, Vec<(u64, u64)>) {
+    let (earliest, buses) = input.split_once('\n').unwrap();
+    (
+        earliest.parse().unwrap(),
+        buses
+            .split(',')
+            .enumerate()
+            .filter(|&(_, id)| id != "x")
+            .map(|(i, id)| (i as u64, id.parse().unwrap()))
+            .collect(),
+    )
+}
+
+pub fn part1(input: &str) -> String {
+    let (earliest, buses) = notes(input);
+    let (wait, id) = buses
+        .iter()
+        .map(|&(_, id)| ((id - earliest % id) % id, id))
+        .min()
+        .unwrap();
+    (wait * id).to_string()
+}
+
+/// Solves the congruences with the Chinese remainder theorem, using modular inverses.
+pub fn part2(input: &str) -> String {
+    let (_, buses) = notes(input);
+    let product: u128 = buses.iter().map(|&(_, id)| u128::from(id)).product();
+    let mut t = 0;
+    for &(offset, id) in &buses {
+        let (offset, id) = (u128::from(offset), u128::from(id));
+        let rest = product / id;
+        let remainder = (id - offset % id) % id;
+        t += remainder * rest % product * inverse(rest % id, id) % product;
+    }
+    (t % product).to_string()
+}
+
+/// `a`'s inverse modulo `m`, by the extended Euclidean algorithm.
+fn inverse(a: u128, m: u128) -> u128 {
+    let (mut r0, mut r1) = (a as i128, m as i128);
+    let (mut s0, mut s1) = (1_i128, 0_i128);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (s0, s1) = (s1, s0 - q * s1);
+    }
+    s0.rem_euclid(m as i128) as u128
+}
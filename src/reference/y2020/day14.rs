@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+/// Each write's address and value, along with the mask in force for it.
+fn writes(input: &str) -> Vec<(&str, u64, u64)> {
+    let mut mask = "";
+    let mut writes = Vec::new();
+    for l in input.lines() {
+        if let Some(m) = l.strip_prefix("mask = ") {
+            mask = m;
+        } else {
+            let (address, value) = l["mem[".len()..].split_once("] = ").unwrap();
+            writes.push((mask, address.parse().unwrap(), value.parse().unwrap()));
+        }
+    }
+    writes
+}
+
+/// Bit `i` of the mask, counting from the least significant.
+fn bit(mask: &str, i: usize) -> u8 {
+    mask.as_bytes()[35 - i]
+}
+
+pub fn part1(input: &str) -> String {
+    let mut memory = HashMap::new();
+    for (mask, address, mut value) in writes(input) {
+        for i in 0..36 {
+            match bit(mask, i) {
+                b'0' => value &= !(1 << i),
+                b'1' => value |= 1 << i,
+                _ => {}
+            }
+        }
+        memory.insert(address, value);
+    }
+    memory.values().sum::<u64>().to_string()
+}
+
+/// Writes every address the floating bits can make, one bit at a time.
+pub fn part2(input: &str) -> String {
+    let mut memory = HashMap::new();
+    for (mask, address, value) in writes(input) {
+        let mut addresses = vec![address];
+        for i in 0..36 {
+            match bit(mask, i) {
+                b'1' => addresses.iter_mut().for_each(|a| *a |= 1 << i),
+                b'X' => {
+                    addresses = addresses
+                        .iter()
+                        .flat_map(|a| [a & !(1 << i), a | 1 << i])
+                        .collect();
+                }
+                _ => {}
+            }
+        }
+        for a in addresses {
+            memory.insert(a, value);
+        }
+    }
+    memory.values().sum::<u64>().to_string()
+}
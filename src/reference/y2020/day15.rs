@@ -0,0 +1,24 @@
+use std::collections::HashMap;
+
+fn spoken(input: &str, turns: usize) -> u64 {
+    let start: Vec<u64> = input.split(',').map(|n| n.parse().unwrap()).collect();
+    let mut last_spoken = HashMap::new();
+    let mut last = start[0];
+    for turn in 1..turns {
+        let next = match start.get(turn) {
+            Some(&n) => n,
+            None => last_spoken.get(&last).map_or(0, |&t| (turn - t) as u64),
+        };
+        last_spoken.insert(last, turn);
+        last = next;
+    }
+    last
+}
+
+pub fn part1(input: &str) -> String {
+    spoken(input, 2020).to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    spoken(input, 30_000_000).to_string()
+}
@@ -0,0 +1,93 @@
+use std::ops::RangeInclusive;
+
+struct Notes<'a> {
+    rules: Vec<(&'a str, [RangeInclusive<u64>; 2])>,
+    mine: Vec<u64>,
+    nearby: Vec<Vec<u64>>,
+}
+
+fn notes(input: &str) -> Notes<'_> {
+    let mut sections = input.split("\n\n");
+    let rules = sections
+        .next()
+        .unwrap()
+        .lines()
+        .map(|l| {
+            let (name, ranges) = l.split_once(": ").unwrap();
+            let range = |r: &str| {
+                let (a, b) = r.split_once('-').unwrap();
+                a.parse().unwrap()..=b.parse().unwrap()
+            };
+            let (a, b) = ranges.split_once(" or ").unwrap();
+            (name, [range(a), range(b)])
+        })
+        .collect();
+    let tickets = |section: Option<&str>| -> Vec<Vec<u64>> {
+        section
+            .unwrap()
+            .lines()
+            .skip(1)
+            .map(|l| l.split(',').map(|v| v.parse().unwrap()).collect())
+            .collect()
+    };
+    let mine = tickets(sections.next()).remove(0);
+    let nearby = tickets(sections.next());
+    Notes {
+        rules,
+        mine,
+        nearby,
+    }
+}
+
+fn fits(ranges: &[RangeInclusive<u64>; 2], value: u64) -> bool {
+    ranges.iter().any(|r| r.contains(&value))
+}
+
+fn fits_any(notes: &Notes, value: u64) -> bool {
+    notes.rules.iter().any(|(_, ranges)| fits(ranges, value))
+}
+
+pub fn part1(input: &str) -> String {
+    let notes = notes(input);
+    notes
+        .nearby
+        .iter()
+        .flatten()
+        .filter(|&&v| !fits_any(&notes, v))
+        .sum::<u64>()
+        .to_string()
+}
+
+/// Keeps placing whichever field fits only one unplaced position.
+pub fn part2(input: &str) -> String {
+    let notes = notes(input);
+    let valid: Vec<_> = notes
+        .nearby
+        .iter()
+        .filter(|t| t.iter().all(|&v| fits_any(&notes, v)))
+        .collect();
+
+    let mut position = vec![None; notes.rules.len()];
+    while position.contains(&None) {
+        let (field, column) = (0..notes.rules.len())
+            .filter(|&f| position[f].is_none())
+            .find_map(|f| {
+                let columns: Vec<_> = (0..notes.mine.len())
+                    .filter(|c| !position.contains(&Some(*c)))
+                    .filter(|&c| valid.iter().all(|t| fits(&notes.rules[f].1, t[c])))
+                    .collect();
+                (columns.len() == 1).then(|| (f, columns[0]))
+            })
+            .unwrap();
+        position[field] = Some(column);
+    }
+
+    notes
+        .rules
+        .iter()
+        .zip(position)
+        .filter(|((name, _), _)| name.starts_with("departure"))
+        .map(|(_, p)| notes.mine[p.unwrap()])
+        .product::<u64>()
+        .to_string()
+}
@@ -0,0 +1,56 @@
+use std::collections::{HashMap, HashSet};
+
+type Cube = [i64; 4];
+
+/// Runs six cycles in `dims` dimensions, the ones past the first two starting at 0.
+fn boot(input: &str, dims: usize) -> usize {
+    let mut active: HashSet<Cube> = HashSet::new();
+    for (y, l) in input.lines().enumerate() {
+        for (x, c) in l.chars().enumerate() {
+            if c == '#' {
+                active.insert([x as i64, y as i64, 0, 0]);
+            }
+        }
+    }
+
+    // Every offset from -1 to 1 in the dimensions in use, but not all zeroes.
+    let mut offsets = vec![[0; 4]];
+    for d in 0..dims {
+        offsets = offsets
+            .iter()
+            .flat_map(|&o| {
+                (-1..=1).map(move |step| {
+                    let mut o = o;
+                    o[d] = step;
+                    o
+                })
+            })
+            .collect();
+    }
+    offsets.retain(|o| o != &[0; 4]);
+
+    for _ in 0..6 {
+        let mut neighbors: HashMap<Cube, usize> = HashMap::new();
+        for cube in &active {
+            for o in &offsets {
+                let n = [0, 1, 2, 3].map(|i| cube[i] + o[i]);
+                *neighbors.entry(n).or_default() += 1;
+            }
+        }
+        active = neighbors
+            .into_iter()
+            .filter(|(cube, n)| *n == 3 || (*n == 2 && active.contains(cube)))
+            .map(|(cube, _)| cube)
+            .collect();
+    }
+
+    active.len()
+}
+
+pub fn part1(input: &str) -> String {
+    boot(input, 3).to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    boot(input, 4).to_string()
+}
@@ -0,0 +1,83 @@
+/// Evaluates by recursive descent, with `+` binding tighter than `*` if `add_first`. Otherwise
+/// they bind the same and go left to right.
+struct Parser<'a> {
+    tokens: &'a [u8],
+    at: usize,
+    add_first: bool,
+}
+
+impl Parser<'_> {
+    fn next(&mut self) -> Option<u8> {
+        let token = self.peek();
+        self.at += 1;
+        token
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        while self.tokens.get(self.at) == Some(&b' ') {
+            self.at += 1;
+        }
+        self.tokens.get(self.at).copied()
+    }
+
+    fn expression(&mut self) -> u64 {
+        if self.add_first {
+            let mut value = self.sum();
+            while self.peek() == Some(b'*') {
+                self.next();
+                value *= self.sum();
+            }
+            value
+        } else {
+            let mut value = self.operand();
+            while let Some(op @ (b'+' | b'*')) = self.peek() {
+                self.next();
+                let rhs = self.operand();
+                value = if op == b'+' { value + rhs } else { value * rhs };
+            }
+            value
+        }
+    }
+
+    fn sum(&mut self) -> u64 {
+        let mut value = self.operand();
+        while self.peek() == Some(b'+') {
+            self.next();
+            value += self.operand();
+        }
+        value
+    }
+
+    fn operand(&mut self) -> u64 {
+        match self.next().unwrap() {
+            b'(' => {
+                let value = self.expression();
+                self.next();
+                value
+            }
+            digit => u64::from(digit - b'0'),
+        }
+    }
+}
+
+fn total(input: &str, add_first: bool) -> u64 {
+    input
+        .lines()
+        .map(|l| {
+            Parser {
+                tokens: l.as_bytes(),
+                at: 0,
+                add_first,
+            }
+            .expression()
+        })
+        .sum()
+}
+
+pub fn part1(input: &str) -> String {
+    total(input, false).to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    total(input, true).to_string()
+}
@@ -0,0 +1,85 @@
+use std::collections::{BTreeSet, HashMap};
+
+enum Rule {
+    Char(u8),
+    Alternatives(Vec<Vec<u32>>),
+}
+
+fn parse(input: &str) -> (HashMap<u32, Rule>, Vec<&[u8]>) {
+    let (rules, messages) = input.split_once("\n\n").unwrap();
+    let rules = rules
+        .lines()
+        .map(|l| {
+            let (id, rule) = l.split_once(": ").unwrap();
+            let rule = match rule.strip_prefix('"') {
+                Some(c) => Rule::Char(c.as_bytes()[0]),
+                None => Rule::Alternatives(
+                    rule.split(" | ")
+                        .map(|seq| seq.split(' ').map(|r| r.parse().unwrap()).collect())
+                        .collect(),
+                ),
+            };
+            (id.parse().unwrap(), rule)
+        })
+        .collect();
+    (rules, messages.lines().map(str::as_bytes).collect())
+}
+
+/// Every position `rule` can stop matching at, having started at `from`. Trying every way
+/// through copes with any grammar, including loops that consume something each time around.
+/// `known` remembers what's been worked out for this message already.
+fn ends(
+    rules: &HashMap<u32, Rule>,
+    rule: u32,
+    message: &[u8],
+    from: usize,
+    known: &mut HashMap<(u32, usize), BTreeSet<usize>>,
+) -> BTreeSet<usize> {
+    if let Some(ends) = known.get(&(rule, from)) {
+        return ends.clone();
+    }
+
+    let ends = match &rules[&rule] {
+        Rule::Char(c) => (message.get(from) == Some(c))
+            .then_some(from + 1)
+            .into_iter()
+            .collect(),
+        Rule::Alternatives(alternatives) => {
+            let mut ends = BTreeSet::new();
+            for seq in alternatives {
+                let mut starts = BTreeSet::from([from]);
+                for &r in seq {
+                    let mut next = BTreeSet::new();
+                    for s in starts.into_iter().filter(|&s| s < message.len()) {
+                        next.extend(self::ends(rules, r, message, s, known));
+                    }
+                    starts = next;
+                }
+                ends.extend(starts);
+            }
+            ends
+        }
+    };
+    known.insert((rule, from), ends.clone());
+    ends
+}
+
+fn matching(rules: &HashMap<u32, Rule>, messages: &[&[u8]]) -> usize {
+    messages
+        .iter()
+        .filter(|m| ends(rules, 0, m, 0, &mut HashMap::new()).contains(&m.len()))
+        .count()
+}
+
+pub fn part1(input: &str) -> String {
+    let (rules, messages) = parse(input);
+    matching(&rules, &messages).to_string()
+}
+
+/// Swaps in the looping rules the puzzle gives.
+pub fn part2(input: &str) -> String {
+    let (mut rules, messages) = parse(input);
+    rules.insert(8, Rule::Alternatives(vec![vec![42], vec![42, 8]]));
+    rules.insert(11, Rule::Alternatives(vec![vec![42, 31], vec![42, 11, 31]]));
+    matching(&rules, &messages).to_string()
+}
@@ -0,0 +1,38 @@
+/// The two numbers, the letter and the password of each line.
+fn policies(input: &str) -> Vec<(usize, usize, char, &str)> {
+    input
+        .lines()
+        .map(|l| {
+            let (policy, password) = l.split_once(": ").unwrap();
+            let (range, letter) = policy.split_once(' ').unwrap();
+            let (a, b) = range.split_once('-').unwrap();
+            (
+                a.parse().unwrap(),
+                b.parse().unwrap(),
+                letter.chars().next().unwrap(),
+                password,
+            )
+        })
+        .collect()
+}
+
+pub fn part1(input: &str) -> String {
+    policies(input)
+        .into_iter()
+        .filter(|&(min, max, letter, password)| {
+            (min..=max).contains(&password.chars().filter(|&c| c == letter).count())
+        })
+        .count()
+        .to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    policies(input)
+        .into_iter()
+        .filter(|&(a, b, letter, password)| {
+            let at = |i: usize| password.chars().nth(i - 1) == Some(letter);
+            at(a) != at(b)
+        })
+        .count()
+        .to_string()
+}
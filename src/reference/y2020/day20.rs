@@ -0,0 +1,161 @@
+use std::collections::{HashMap, HashSet};
+
+/// Each tile's id and four borders.
+fn tiles(input: &str) -> Vec<(u64, [String; 4])> {
+    input
+        .split("\n\n")
+        .map(|t| {
+            let mut lines = t.lines();
+            let id = lines.next().unwrap()["Tile ".len()..].trim_end_matches(':');
+            let rows: Vec<&str> = lines.collect();
+            let column = |i: usize| rows.iter().map(|r| &r[i..=i]).collect::<String>();
+            (
+                id.parse().unwrap(),
+                [
+                    rows[0].to_string(),
+                    rows[rows.len() - 1].to_string(),
+                    column(0),
+                    column(rows[0].len() - 1),
+                ],
+            )
+        })
+        .collect()
+}
+
+/// Corners are the tiles with only two borders that some other tile also has, either way round.
+pub fn part1(input: &str) -> String {
+    let tiles = tiles(input);
+    let mut seen = HashMap::new();
+    for border in tiles.iter().flat_map(|(_, borders)| borders) {
+        *seen.entry(border.clone()).or_insert(0) += 1;
+        *seen.entry(border.chars().rev().collect()).or_insert(0) += 1;
+    }
+
+    tiles
+        .iter()
+        .filter(|(_, borders)| borders.iter().filter(|b| seen[*b] > 1).count() == 2)
+        .map(|(id, _)| id)
+        .product::<u64>()
+        .to_string()
+}
+
+type Square = Vec<Vec<char>>;
+
+/// Turned a quarter clockwise.
+fn turn(square: &Square) -> Square {
+    (0..square.len())
+        .map(|c| square.iter().rev().map(|row| row[c]).collect())
+        .collect()
+}
+
+/// All 8 ways of turning and flipping `square`.
+fn orientations(square: &Square) -> Vec<Square> {
+    let flipped = square.iter().rev().cloned().collect();
+    let mut all = Vec::new();
+    for mut s in [square.clone(), flipped] {
+        for _ in 0..4 {
+            let next = turn(&s);
+            all.push(s);
+            s = next;
+        }
+    }
+    all
+}
+
+fn column(square: &Square, c: usize) -> Vec<char> {
+    square.iter().map(|row| row[c]).collect()
+}
+
+/// Tries every tile in every orientation in each place in turn, starting from a top left corner
+/// whose top and left borders no other tile has, then cuts off the borders and looks for sea
+/// monsters in every orientation of the picture.
+pub fn part2(input: &str) -> String {
+    let tiles: Vec<Vec<Square>> = input
+        .split("\n\n")
+        .map(|t| orientations(&t.lines().skip(1).map(|l| l.chars().collect()).collect()))
+        .collect();
+    let side = tiles.len().isqrt();
+    let n = tiles[0][0].len();
+
+    let shared = |i: usize, border: &[char]| {
+        tiles.iter().enumerate().any(|(j, other)| {
+            j != i
+                && other
+                    .iter()
+                    .any(|o| o[0] == border || column(o, 0) == border)
+        })
+    };
+
+    let mut placed: Vec<(usize, &Square)> = Vec::new();
+    'corners: for (i, tile) in tiles.iter().enumerate() {
+        'orientations: for o in tile {
+            if shared(i, &o[0]) || shared(i, &column(o, 0)) {
+                continue;
+            }
+            placed = vec![(i, o)];
+            while placed.len() < tiles.len() {
+                let k = placed.len();
+                let next = tiles.iter().enumerate().find_map(|(j, tile)| {
+                    if placed.iter().any(|&(p, _)| p == j) {
+                        return None;
+                    }
+                    tile.iter()
+                        .find(|o| {
+                            (k.is_multiple_of(side)
+                                || column(placed[k - 1].1, n - 1) == column(o, 0))
+                                && (k < side || placed[k - side].1[n - 1] == o[0])
+                        })
+                        .map(|o| (j, o))
+                });
+                match next {
+                    Some(next) => placed.push(next),
+                    None => continue 'orientations,
+                }
+            }
+            break 'corners;
+        }
+    }
+
+    let picture: Square = (0..side * (n - 2))
+        .map(|r| {
+            (0..side * (n - 2))
+                .map(|c| {
+                    placed[r / (n - 2) * side + c / (n - 2)].1[r % (n - 2) + 1][c % (n - 2) + 1]
+                })
+                .collect()
+        })
+        .collect();
+
+    let monster = [
+        "                  # ",
+        "#    ##    ##    ###",
+        " #  #  #  #  #  #   ",
+    ];
+    let rough = picture.iter().flatten().filter(|&&c| c == '#').count();
+    for picture in orientations(&picture) {
+        let mut seen = HashSet::new();
+        for r in 0..picture.len() {
+            for c in 0..picture.len() {
+                let cells: Vec<_> = monster
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(dr, row)| {
+                        row.char_indices()
+                            .filter(|&(_, m)| m == '#')
+                            .map(move |(dc, _)| (r + dr, c + dc))
+                    })
+                    .collect();
+                let found = cells
+                    .iter()
+                    .all(|&(y, x)| picture.get(y).and_then(|row| row.get(x)) == Some(&'#'));
+                if found {
+                    seen.extend(cells);
+                }
+            }
+        }
+        if !seen.is_empty() {
+            return (rough - seen.len()).to_string();
+        }
+    }
+    panic!("no sea monsters")
+}
@@ -0,0 +1,64 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+fn foods(input: &str) -> Vec<(BTreeSet<&str>, Vec<&str>)> {
+    input
+        .lines()
+        .map(|l| {
+            let (ingredients, allergens) = l.split_once(" (contains ").unwrap_or((l, ")"));
+            (
+                ingredients.split(' ').collect(),
+                allergens
+                    .trim_end_matches(')')
+                    .split(", ")
+                    .filter(|a| !a.is_empty())
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+/// Which ingredient has each allergen. An ingredient can only have an allergen if it's in
+/// every food listing it, and whichever allergen has one option left settles that ingredient.
+fn dangerous<'a>(foods: &[(BTreeSet<&'a str>, Vec<&'a str>)]) -> BTreeMap<&'a str, &'a str> {
+    let mut options: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    for (ingredients, allergens) in foods {
+        for a in allergens {
+            let o = options.entry(a).or_insert_with(|| ingredients.clone());
+            *o = o.intersection(ingredients).copied().collect();
+        }
+    }
+
+    let mut settled = BTreeMap::new();
+    while settled.len() < options.len() {
+        let (&a, o) = options
+            .iter()
+            .find(|(a, o)| !settled.contains_key(*a) && o.len() == 1)
+            .unwrap();
+        let i = *o.iter().next().unwrap();
+        settled.insert(a, i);
+        for o in options.values_mut() {
+            if o.len() > 1 {
+                o.remove(i);
+            }
+        }
+    }
+    settled
+}
+
+pub fn part1(input: &str) -> String {
+    let foods = foods(input);
+    let dangerous = dangerous(&foods);
+    foods
+        .iter()
+        .flat_map(|(ingredients, _)| ingredients)
+        .filter(|i| !dangerous.values().any(|d| d == *i))
+        .count()
+        .to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    dangerous(&foods(input))
+        .into_values()
+        .collect::<Vec<_>>()
+        .join(",")
+}
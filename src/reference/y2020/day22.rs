@@ -0,0 +1,57 @@
+use std::collections::HashSet;
+
+fn decks(input: &str) -> (Vec<usize>, Vec<usize>) {
+    let (one, two) = input.split_once("\n\n").unwrap();
+    let deck = |d: &str| d.lines().skip(1).map(|c| c.parse().unwrap()).collect();
+    (deck(one), deck(two))
+}
+
+fn score(deck: &[usize]) -> usize {
+    deck.iter()
+        .enumerate()
+        .map(|(i, c)| c * (deck.len() - i))
+        .sum()
+}
+
+/// Plays to the end, and is true if player 1 won.
+fn play(one: &mut Vec<usize>, two: &mut Vec<usize>, recursive: bool) -> bool {
+    let mut seen = HashSet::new();
+    while !one.is_empty() && !two.is_empty() {
+        if recursive && !seen.insert((one.clone(), two.clone())) {
+            return true;
+        }
+
+        let a = one.remove(0);
+        let b = two.remove(0);
+        let one_wins = if recursive && a <= one.len() && b <= two.len() {
+            play(&mut one[..a].to_vec(), &mut two[..b].to_vec(), true)
+        } else {
+            a > b
+        };
+
+        if one_wins {
+            one.extend([a, b]);
+        } else {
+            two.extend([b, a]);
+        }
+    }
+    two.is_empty()
+}
+
+fn winning_score(input: &str, recursive: bool) -> String {
+    let (mut one, mut two) = decks(input);
+    let winner = if play(&mut one, &mut two, recursive) {
+        one
+    } else {
+        two
+    };
+    score(&winner).to_string()
+}
+
+pub fn part1(input: &str) -> String {
+    winning_score(input, false)
+}
+
+pub fn part2(input: &str) -> String {
+    winning_score(input, true)
+}
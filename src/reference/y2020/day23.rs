@@ -0,0 +1,75 @@
+pub fn part1(input: &str) -> String {
+    let mut cups: Vec<u32> = input.chars().map(|c| c.to_digit(10).unwrap()).collect();
+    let highest = *cups.iter().max().unwrap();
+
+    for _ in 0..100 {
+        // The current cup is always at the front.
+        let current = cups[0];
+        let picked: Vec<u32> = cups.drain(1..4).collect();
+        let mut destination = current;
+        loop {
+            destination = if destination == 1 {
+                highest
+            } else {
+                destination - 1
+            };
+            if !picked.contains(&destination) {
+                break;
+            }
+        }
+        let at = cups.iter().position(|&c| c == destination).unwrap();
+        for (i, cup) in picked.into_iter().enumerate() {
+            cups.insert(at + 1 + i, cup);
+        }
+        cups.rotate_left(1);
+    }
+
+    let one = cups.iter().position(|&c| c == 1).unwrap();
+    cups.rotate_left(one);
+    cups[1..].iter().map(ToString::to_string).collect()
+}
+
+/// Too many cups to move around in a list, so each cup just knows which is next.
+pub fn part2(input: &str) -> String {
+    const CUPS: usize = 1_000_000;
+
+    let start: Vec<usize> = input
+        .chars()
+        .map(|c| c.to_digit(10).unwrap() as usize)
+        .collect();
+    let order: Vec<usize> = start
+        .iter()
+        .copied()
+        .chain(start.len() + 1..=CUPS)
+        .collect();
+    let mut next = vec![0; CUPS + 1];
+    for (&a, &b) in order.iter().zip(order.iter().cycle().skip(1)) {
+        next[a] = b;
+    }
+
+    let mut current = order[0];
+    for _ in 0..10_000_000 {
+        let picked = [
+            next[current],
+            next[next[current]],
+            next[next[next[current]]],
+        ];
+        let mut destination = current;
+        loop {
+            destination = if destination == 1 {
+                CUPS
+            } else {
+                destination - 1
+            };
+            if !picked.contains(&destination) {
+                break;
+            }
+        }
+        next[current] = next[picked[2]];
+        next[picked[2]] = next[destination];
+        next[destination] = picked[0];
+        current = next[current];
+    }
+
+    (next[1] as u64 * next[next[1]] as u64).to_string()
+}
@@ -0,0 +1,71 @@
+use std::collections::HashSet;
+
+/// Doubled coordinates: east and west move two columns, the rest one column and one row.
+fn tile(line: &str) -> (i64, i64) {
+    let (mut x, mut y) = (0, 0);
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        let (dx, dy) = match c {
+            'e' => (2, 0),
+            'w' => (-2, 0),
+            'n' | 's' => {
+                let dy = if c == 'n' { -1 } else { 1 };
+                match chars.next().unwrap() {
+                    'e' => (1, dy),
+                    _ => (-1, dy),
+                }
+            }
+            _ => panic!("bad direction {c}", c = c),
+        };
+        x += dx;
+        y += dy;
+    }
+    (x, y)
+}
+
+fn neighbors((x, y): (i64, i64)) -> [(i64, i64); 6] {
+    [
+        (x + 2, y),
+        (x - 2, y),
+        (x + 1, y - 1),
+        (x - 1, y - 1),
+        (x + 1, y + 1),
+        (x - 1, y + 1),
+    ]
+}
+
+fn black(input: &str) -> HashSet<(i64, i64)> {
+    let mut black = HashSet::new();
+    for line in input.lines() {
+        let t = tile(line);
+        if black.contains(&t) {
+            black.remove(&t);
+        } else {
+            black.insert(t);
+        }
+    }
+    black
+}
+
+pub fn part1(input: &str) -> String {
+    black(input).len().to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    let mut black = black(input);
+    for _ in 0..100 {
+        let nearby: HashSet<_> = black
+            .iter()
+            .flat_map(|&t| neighbors(t))
+            .chain(black.iter().copied())
+            .collect();
+        black = nearby
+            .into_iter()
+            .filter(|&t| {
+                let n = neighbors(t).iter().filter(|n| black.contains(n)).count();
+                n == 2 || (n == 1 && black.contains(&t))
+            })
+            .collect();
+    }
+    black.len().to_string()
+}
@@ -0,0 +1,16 @@
+pub fn part1(input: &str) -> String {
+    let keys: Vec<u64> = input.lines().map(|l| l.parse().unwrap()).collect();
+
+    let mut value = 1;
+    let mut loop_size = 0;
+    while value != keys[0] {
+        value = value * 7 % 20_201_227;
+        loop_size += 1;
+    }
+
+    let mut key = 1;
+    for _ in 0..loop_size {
+        key = key * keys[1] % 20_201_227;
+    }
+    key.to_string()
+}
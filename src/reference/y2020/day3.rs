@@ -0,0 +1,24 @@
+fn trees(input: &str, right: usize, down: usize) -> u64 {
+    let rows: Vec<Vec<char>> = input.lines().map(|l| l.chars().collect()).collect();
+    let mut count = 0;
+    let mut col = 0;
+    for row in rows.iter().step_by(down) {
+        if row[col % row.len()] == '#' {
+            count += 1;
+        }
+        col += right;
+    }
+    count
+}
+
+pub fn part1(input: &str) -> String {
+    trees(input, 3, 1).to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
+        .iter()
+        .map(|&(right, down)| trees(input, right, down))
+        .product::<u64>()
+        .to_string()
+}
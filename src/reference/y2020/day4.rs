@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+
+fn passports(input: &str) -> Vec<HashMap<&str, &str>> {
+    input
+        .split("\n\n")
+        .map(|p| {
+            p.split_whitespace()
+                .map(|field| field.split_once(':').unwrap())
+                .collect()
+        })
+        .collect()
+}
+
+const REQUIRED: [&str; 7] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+
+fn complete(passport: &HashMap<&str, &str>) -> bool {
+    REQUIRED.iter().all(|f| passport.contains_key(f))
+}
+
+fn valid(field: &str, value: &str) -> bool {
+    let year =
+        |min, max| value.len() == 4 && value.parse::<u32>().is_ok_and(|y| (min..=max).contains(&y));
+
+    match field {
+        "byr" => year(1920, 2002),
+        "iyr" => year(2010, 2020),
+        "eyr" => year(2020, 2030),
+        "hgt" => {
+            let (number, range) = if let Some(cm) = value.strip_suffix("cm") {
+                (cm, 150..=193)
+            } else if let Some(inches) = value.strip_suffix("in") {
+                (inches, 59..=76)
+            } else {
+                return false;
+            };
+            number.parse::<u32>().is_ok_and(|h| range.contains(&h))
+        }
+        "hcl" => value.strip_prefix('#').is_some_and(|hex| {
+            hex.len() == 6 && hex.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f'))
+        }),
+        "ecl" => ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"].contains(&value),
+        "pid" => value.len() == 9 && value.chars().all(|c| c.is_ascii_digit()),
+        _ => true,
+    }
+}
+
+pub fn part1(input: &str) -> String {
+    passports(input)
+        .iter()
+        .filter(|p| complete(p))
+        .count()
+        .to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    passports(input)
+        .iter()
+        .filter(|p| complete(p) && p.iter().all(|(f, v)| valid(f, v)))
+        .count()
+        .to_string()
+}
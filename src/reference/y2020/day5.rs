@@ -0,0 +1,36 @@
+use std::collections::BTreeSet;
+
+/// Narrows the rows and columns down one half at a time, like the puzzle describes.
+fn seat(pass: &str) -> u32 {
+    let (mut rows, mut cols) = ((0_u32, 127), (0, 7));
+    for c in pass.chars() {
+        let (low, high) = if matches!(c, 'F' | 'B') {
+            &mut rows
+        } else {
+            &mut cols
+        };
+        let middle = low.midpoint(*high);
+        if matches!(c, 'F' | 'L') {
+            *high = middle;
+        } else {
+            *low = middle + 1;
+        }
+    }
+    rows.0 * 8 + cols.0
+}
+
+fn seats(input: &str) -> BTreeSet<u32> {
+    input.lines().map(seat).collect()
+}
+
+pub fn part1(input: &str) -> String {
+    seats(input).iter().max().unwrap().to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    let seats = seats(input);
+    (1..1024)
+        .find(|id| !seats.contains(id) && seats.contains(&(id - 1)) && seats.contains(&(id + 1)))
+        .unwrap()
+        .to_string()
+}
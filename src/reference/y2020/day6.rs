@@ -0,0 +1,28 @@
+use std::collections::HashSet;
+
+fn groups(input: &str) -> Vec<Vec<HashSet<char>>> {
+    input
+        .split("\n\n")
+        .map(|g| g.lines().map(|p| p.chars().collect()).collect())
+        .collect()
+}
+
+pub fn part1(input: &str) -> String {
+    groups(input)
+        .iter()
+        .map(|g| g.iter().flatten().collect::<HashSet<_>>().len())
+        .sum::<usize>()
+        .to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    groups(input)
+        .iter()
+        .map(|g| {
+            ('a'..='z')
+                .filter(|c| g.iter().all(|p| p.contains(c)))
+                .count()
+        })
+        .sum::<usize>()
+        .to_string()
+}
@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+
+/// Each colour and what it holds.
+fn rules(input: &str) -> HashMap<&str, Vec<(usize, &str)>> {
+    input
+        .lines()
+        .map(|l| {
+            let (outer, inner) = l.split_once(" bags contain ").unwrap();
+            let held = inner
+                .trim_end_matches('.')
+                .split(", ")
+                .filter(|b| *b != "no other bags")
+                .map(|b| {
+                    let (count, rest) = b.split_once(' ').unwrap();
+                    let color = rest.trim_end_matches(" bags").trim_end_matches(" bag");
+                    (count.parse().unwrap(), color)
+                })
+                .collect();
+            (outer, held)
+        })
+        .collect()
+}
+
+/// Remembers what it's already worked out, since the same bags turn up inside many others.
+fn holds_gold<'a>(
+    rules: &HashMap<&'a str, Vec<(usize, &'a str)>>,
+    known: &mut HashMap<&'a str, bool>,
+    color: &'a str,
+) -> bool {
+    if let Some(&holds) = known.get(color) {
+        return holds;
+    }
+    let holds = rules[color]
+        .iter()
+        .any(|&(_, inner)| inner == "shiny gold" || holds_gold(rules, known, inner));
+    known.insert(color, holds);
+    holds
+}
+
+fn inside(rules: &HashMap<&str, Vec<(usize, &str)>>, color: &str) -> usize {
+    rules[color]
+        .iter()
+        .map(|&(count, inner)| count * (1 + inside(rules, inner)))
+        .sum()
+}
+
+pub fn part1(input: &str) -> String {
+    let rules = rules(input);
+    let mut known = HashMap::new();
+    rules
+        .keys()
+        .filter(|c| holds_gold(&rules, &mut known, c))
+        .count()
+        .to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    inside(&rules(input), "shiny gold").to_string()
+}
@@ -0,0 +1,52 @@
+fn program(input: &str) -> Vec<(&str, i64)> {
+    input
+        .lines()
+        .map(|l| {
+            let (op, arg) = l.split_once(' ').unwrap();
+            (op, arg.parse().unwrap())
+        })
+        .collect()
+}
+
+/// The accumulator when the program either loops or ends, and whether it ended.
+fn run(program: &[(&str, i64)]) -> (i64, bool) {
+    let mut seen = vec![false; program.len()];
+    let (mut acc, mut pc) = (0, 0);
+    while pc != program.len() as i64 {
+        if pc < 0 || pc > program.len() as i64 || seen[pc as usize] {
+            return (acc, false);
+        }
+        seen[pc as usize] = true;
+        match program[pc as usize] {
+            ("acc", x) => {
+                acc += x;
+                pc += 1;
+            }
+            ("jmp", x) => pc += x,
+            _ => pc += 1,
+        }
+    }
+    (acc, true)
+}
+
+pub fn part1(input: &str) -> String {
+    run(&program(input)).0.to_string()
+}
+
+/// Tries every swap in turn.
+pub fn part2(input: &str) -> String {
+    let mut program = program(input);
+    for i in 0..program.len() {
+        let original = program[i].0;
+        program[i].0 = match original {
+            "jmp" => "nop",
+            "nop" => "jmp",
+            _ => continue,
+        };
+        if let (acc, true) = run(&program) {
+            return acc.to_string();
+        }
+        program[i].0 = original;
+    }
+    panic!("no swap makes the program end")
+}
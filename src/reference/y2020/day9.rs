@@ -0,0 +1,37 @@
+fn numbers(input: &str) -> Vec<u64> {
+    input.lines().map(|l| l.parse().unwrap()).collect()
+}
+
+fn invalid(numbers: &[u64]) -> u64 {
+    (25..numbers.len())
+        .map(|i| (&numbers[i - 25..i], numbers[i]))
+        .find(|(window, n)| {
+            !window
+                .iter()
+                .enumerate()
+                .any(|(j, a)| window[j + 1..].iter().any(|b| a + b == *n))
+        })
+        .unwrap()
+        .1
+}
+
+pub fn part1(input: &str) -> String {
+    invalid(&numbers(input)).to_string()
+}
+
+/// Adds up every run of two or more numbers until one hits the target.
+pub fn part2(input: &str) -> String {
+    let numbers = numbers(input);
+    let target = invalid(&numbers);
+    for start in 0..numbers.len() {
+        let mut sum = numbers[start];
+        for end in start + 1..numbers.len() {
+            sum += numbers[end];
+            if sum == target {
+                let run = &numbers[start..=end];
+                return (run.iter().min().unwrap() + run.iter().max().unwrap()).to_string();
+            }
+        }
+    }
+    panic!("no run adds up to the invalid number")
+}
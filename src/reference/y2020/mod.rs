@@ -0,0 +1,58 @@
+mod day1;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day2;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod day24;
+mod day25;
+mod day3;
+mod day4;
+mod day5;
+mod day6;
+mod day7;
+mod day8;
+mod day9;
+
+use super::Reference;
+use crate::years::y2020;
+
+pub fn references() -> Vec<Reference> {
+    vec![
+        Reference::new::<y2020::day1::Day1>(day1::part1, Some(day1::part2)),
+        Reference::new::<y2020::day2::Day2>(day2::part1, Some(day2::part2)),
+        Reference::new::<y2020::day3::Day3>(day3::part1, Some(day3::part2)),
+        Reference::new::<y2020::day4::Day4>(day4::part1, Some(day4::part2)),
+        Reference::new::<y2020::day5::Day5>(day5::part1, Some(day5::part2)),
+        Reference::new::<y2020::day6::Day6>(day6::part1, Some(day6::part2)),
+        Reference::new::<y2020::day7::Day7>(day7::part1, Some(day7::part2)),
+        Reference::new::<y2020::day8::Day8>(day8::part1, Some(day8::part2)),
+        Reference::new::<y2020::day9::Day9>(day9::part1, Some(day9::part2)),
+        Reference::new::<y2020::day10::Day10>(day10::part1, Some(day10::part2)),
+        Reference::new::<y2020::day11::Day11>(day11::part1, Some(day11::part2)),
+        Reference::new::<y2020::day12::Day12>(day12::part1, Some(day12::part2)),
+        Reference::new::<y2020::day13::Day13>(day13::part1, Some(day13::part2)),
+        Reference::new::<y2020::day14::Day14>(day14::part1, Some(day14::part2)),
+        Reference::new::<y2020::day15::Day15>(day15::part1, Some(day15::part2)),
+        Reference::new::<y2020::day16::Day16>(day16::part1, Some(day16::part2)),
+        Reference::new::<y2020::day17::Day17>(day17::part1, Some(day17::part2)),
+        Reference::new::<y2020::day18::Day18>(day18::part1, Some(day18::part2)),
+        Reference::new::<y2020::day19::Day19>(day19::part1, Some(day19::part2)),
+        Reference::new::<y2020::day20::Day20>(day20::part1, Some(day20::part2)),
+        Reference::new::<y2020::day21::Day21>(day21::part1, Some(day21::part2)),
+        Reference::new::<y2020::day22::Day22>(day22::part1, Some(day22::part2)),
+        Reference::new::<y2020::day23::Day23>(day23::part1, Some(day23::part2)),
+        Reference::new::<y2020::day24::Day24>(day24::part1, Some(day24::part2)),
+        Reference::new::<y2020::day25::Day25>(day25::part1, None),
+    ]
+}
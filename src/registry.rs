@@ -0,0 +1,613 @@
+use std::{
+    fmt::Display,
+    io::BufRead,
+    panic::{self, AssertUnwindSafe},
+    sync::{mpsc, Arc, Mutex, OnceLock},
+    thread,
+    time::Duration,
+};
+
+use criterion::{measurement::WallTime, BatchSize, BenchmarkGroup};
+
+use crate::{
+    automaton::Backend,
+    day_solver::{DaySolver, Stage, StreamingDaySolver},
+    error::{Error, Result},
+    years::*,
+};
+
+/// The answers a solver produced, indexed by part.
+pub type Answers = [Option<String>; 2];
+
+/// How each stage of a run went. `None` for any that weren't run.
+#[derive(Clone, Debug)]
+pub struct Outcomes {
+    /// Indexed by part.
+    pub parts: [Option<Result<String>>; 2],
+    /// Both answers from [`DaySolver::solve_both`], for days that have one.
+    pub both: Option<Result<[String; 2]>>,
+}
+
+/// Which parts to run, indexed by part.
+pub type Parts = [bool; 2];
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    /// Both parts are solved.
+    Complete,
+    /// Only part 1 is solved, part 2 is never run.
+    Partial,
+}
+
+/// Hooks called around every stage a solver runs, for timing and the like. The parts can run
+/// at the same time on different threads, but each stage starts and finishes on the same one.
+pub trait Probe: Send {
+    fn start(&mut self, stage: Stage);
+    fn finish(&mut self, stage: Stage);
+}
+
+/// Doesn't observe anything.
+impl Probe for () {
+    fn start(&mut self, _: Stage) {}
+    fn finish(&mut self, _: Stage) {}
+}
+
+/// A probe that stages on other threads can report to.
+pub type SharedProbe = Arc<Mutex<dyn Probe>>;
+
+type RunFn = fn(u8, &str, Parts, &mut dyn Probe) -> Result<Answers>;
+type IsolatedFn = fn(u8, &'static str, Parts, Option<Duration>, &SharedProbe) -> Result<Outcomes>;
+type ReaderFn =
+    fn(u8, Box<dyn BufRead + Send>, Parts, Option<Duration>, &SharedProbe) -> Result<Outcomes>;
+type BenchFn = fn(u8, &str, Parts, &mut BenchmarkGroup<'_, WallTime>) -> Result<()>;
+
+/// A type-erased [`DaySolver`] along with what's known about it.
+#[derive(Copy, Clone)]
+pub struct Solver {
+    year: u16,
+    day: u8,
+    slow: bool,
+    status: Status,
+    both: bool,
+    run: RunFn,
+    run_isolated: IsolatedFn,
+    run_reader: Option<ReaderFn>,
+    bench: BenchFn,
+    backends: Option<fn(Backend) -> Self>,
+}
+
+impl Solver {
+    #[must_use]
+    pub fn new<S: for<'a> DaySolver<'a> + 'static>() -> Self {
+        Self {
+            year: <S as DaySolver<'static>>::YEAR,
+            day: <S as DaySolver<'static>>::DAY,
+            slow: false,
+            status: Status::Complete,
+            both: <S as DaySolver<'static>>::SOLVES_BOTH,
+            run: run::<S>,
+            run_isolated: run_isolated::<S>,
+            run_reader: None,
+            bench: bench::<S>,
+            backends: None,
+        }
+    }
+
+    /// Like [`Self::new`], for days that can also parse their input from a reader.
+    pub fn streaming<S: for<'a> StreamingDaySolver<'a> + 'static>() -> Self {
+        Self {
+            run_reader: Some(run_reader::<S>),
+            ..Self::new::<S>()
+        }
+    }
+
+    /// For days that run a cellular automaton, where `backends` gives the day's solver for each
+    /// [`Backend`].
+    #[must_use]
+    pub fn backends(mut self, backends: fn(Backend) -> Self) -> Self {
+        self.backends = Some(backends);
+        self
+    }
+
+    /// The same day, solved with `backend` if it runs a cellular automaton.
+    #[must_use]
+    pub fn with_backend(&self, backend: Backend) -> Self {
+        self.backends.map_or(*self, |backends| {
+            let solver = backends(backend);
+            Self {
+                run: solver.run,
+                run_isolated: solver.run_isolated,
+                run_reader: solver.run_reader,
+                bench: solver.bench,
+                ..*self
+            }
+        })
+    }
+
+    /// Marks the solver as too slow to run in debug builds by default.
+    #[must_use]
+    pub fn slow(mut self) -> Self {
+        self.slow = true;
+        self
+    }
+
+    #[must_use]
+    pub fn partial(mut self) -> Self {
+        self.status = Status::Partial;
+        self
+    }
+
+    #[must_use]
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    #[must_use]
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    #[must_use]
+    pub fn is_slow(&self) -> bool {
+        self.slow
+    }
+
+    #[must_use]
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    /// Whether `part`, which is 1 or 2, has a solution.
+    #[must_use]
+    pub fn solves(&self, part: u8) -> bool {
+        part == 1 || self.status == Status::Complete
+    }
+
+    /// Whether the day can solve both parts in one pass, which runs alongside the separate
+    /// parts when both are wanted.
+    #[must_use]
+    pub fn solves_both(&self) -> bool {
+        self.both && self.solves(2)
+    }
+
+    /// Whether the day can parse its input a line at a time, through [`Self::run_reader`].
+    #[must_use]
+    pub fn streams(&self) -> bool {
+        self.run_reader.is_some()
+    }
+
+    fn mask(&self, parts: Parts) -> Parts {
+        [parts[0] && self.solves(1), parts[1] && self.solves(2)]
+    }
+
+    /// Parses `input` and solves the wanted `parts`, skipping any that aren't solved.
+    ///
+    /// # Errors
+    ///
+    /// The first error from parsing or either part.
+    pub fn run(&self, input: &str, parts: Parts, probe: &mut dyn Probe) -> Result<Answers> {
+        (self.run)(self.day, input, self.mask(parts), probe)
+    }
+
+    /// Like [`Self::run`], but runs every stage on a thread of its own so that a panic only
+    /// fails that stage, and gives up on any that take longer than `timeout`. Those keep running
+    /// in the background, which is why `input` has to live forever.
+    ///
+    /// # Errors
+    ///
+    /// If parsing failed. The parts' errors are in the [`Outcomes`].
+    pub fn run_isolated(
+        &self,
+        input: &'static str,
+        parts: Parts,
+        timeout: Option<Duration>,
+        probe: &SharedProbe,
+    ) -> Result<Outcomes> {
+        (self.run_isolated)(self.day, input, self.mask(parts), timeout, probe)
+    }
+
+    /// Like [`Self::run_isolated`], but parses the input from `reader` a line at a time, so its
+    /// text never has to be held in memory. `None` for days that can't.
+    pub fn run_reader(
+        &self,
+        reader: Box<dyn BufRead + Send>,
+        parts: Parts,
+        timeout: Option<Duration>,
+        probe: &SharedProbe,
+    ) -> Option<Result<Outcomes>> {
+        self.run_reader
+            .map(|run| run(self.day, reader, self.mask(parts), timeout, probe))
+    }
+
+    /// Benchmarks parsing `input` and the wanted `parts` into `group`.
+    ///
+    /// # Errors
+    ///
+    /// If `input` doesn't parse, which leaves the parts unbenchmarked.
+    pub fn bench(
+        &self,
+        input: &str,
+        parts: Parts,
+        group: &mut BenchmarkGroup<'_, WallTime>,
+    ) -> Result<()> {
+        (self.bench)(self.day, input, self.mask(parts), group)
+    }
+}
+
+fn run<S: for<'a> DaySolver<'a>>(
+    day: u8,
+    input: &str,
+    parts: Parts,
+    probe: &mut dyn Probe,
+) -> Result<Answers> {
+    probe.start(Stage::Parse);
+    let parsed = S::parse(input);
+    probe.finish(Stage::Parse);
+    let parsed = parsed.map_err(|e| e.context(day, Stage::Parse))?;
+
+    // Both parts get run at once if the current rayon pool has a thread to spare.
+    let parsed = Arc::new(parsed);
+    let data1 = parts[0].then(|| Arc::clone(&parsed));
+    let data2 = parts[1].then_some(parsed);
+    let probe = Mutex::new(probe);
+    let (answer1, answer2) = rayon::join(
+        || data1.map(|d| solve(&probe, day, Stage::Part1, solve_part::<S>, d)),
+        || data2.map(|d| solve(&probe, day, Stage::Part2, solve_part::<S>, d)),
+    );
+
+    Ok([answer1.transpose()?, answer2.transpose()?])
+}
+
+fn solve<P, O: Display>(
+    probe: &Mutex<&mut dyn Probe>,
+    day: u8,
+    stage: Stage,
+    part: impl FnOnce(Stage, P) -> Result<O>,
+    data: P,
+) -> Result<String> {
+    probe.lock().unwrap().start(stage);
+    let answer = part(stage, data);
+    probe.lock().unwrap().finish(stage);
+    Ok(answer.map_err(|e| e.context(day, stage))?.to_string())
+}
+
+fn run_isolated<S: for<'a> DaySolver<'a> + 'static>(
+    day: u8,
+    input: &'static str,
+    parts: Parts,
+    timeout: Option<Duration>,
+    probe: &SharedProbe,
+) -> Result<Outcomes> {
+    let parsed = isolate(day, Stage::Parse, timeout, probe, move || S::parse(input))?;
+    Ok(solve_isolated::<S>(day, parsed, parts, timeout, probe))
+}
+
+fn run_reader<S: for<'a> StreamingDaySolver<'a> + 'static>(
+    day: u8,
+    reader: Box<dyn BufRead + Send>,
+    parts: Parts,
+    timeout: Option<Duration>,
+    probe: &SharedProbe,
+) -> Result<Outcomes> {
+    let parsed = isolate(day, Stage::Parse, timeout, probe, move || {
+        <S as StreamingDaySolver>::parse_reader(reader)
+    })?;
+    Ok(solve_isolated::<S>(day, parsed, parts, timeout, probe))
+}
+
+/// The isolated stages of [`run_isolated`] that come after parsing.
+fn solve_isolated<S: for<'a> DaySolver<'a> + 'static>(
+    day: u8,
+    parsed: <S as DaySolver<'static>>::Parsed,
+    parts: Parts,
+    timeout: Option<Duration>,
+    probe: &SharedProbe,
+) -> Outcomes {
+    let parsed = Arc::new(parsed);
+    // Done first so the parts can still take the parsed input over afterwards.
+    let both = (S::SOLVES_BOTH && parts == [true, true]).then(|| {
+        let data = Arc::clone(&parsed);
+        isolate(day, Stage::Both, timeout, probe, move || {
+            let (answer1, answer2) = S::solve_both(&data)?;
+            Ok([answer1.to_string(), answer2.to_string()])
+        })
+    });
+
+    let data1 = parts[0].then(|| Arc::clone(&parsed));
+    let data2 = parts[1].then_some(parsed);
+    let isolate_part = |stage, data| {
+        isolate(day, stage, timeout, probe, move || {
+            Ok(solve_part::<S>(stage, data)?.to_string())
+        })
+    };
+    let parts = rayon::join(
+        || data1.map(|d| isolate_part(Stage::Part1, d)),
+        || data2.map(|d| isolate_part(Stage::Part2, d)),
+    );
+
+    Outcomes {
+        parts: parts.into(),
+        both,
+    }
+}
+
+/// Solves one part, only giving parts that consume the parsed input a copy of it if something
+/// else still holds on to it.
+fn solve_part<'a, S: DaySolver<'a>>(stage: Stage, data: Arc<S::Parsed>) -> Result<S::Output> {
+    let part = usize::from(stage == Stage::Part2);
+    if S::CONSUMES[part] {
+        let data = Arc::try_unwrap(data).unwrap_or_else(|d| (*d).clone());
+        [S::part1_owned, S::part2_owned][part](data)
+    } else {
+        [S::part1, S::part2][part](&data)
+    }
+}
+
+fn isolate<T: Send + 'static>(
+    day: u8,
+    stage: Stage,
+    timeout: Option<Duration>,
+    probe: &SharedProbe,
+    f: impl FnOnce() -> Result<T> + Send + 'static,
+) -> Result<T> {
+    let (tx, rx) = mpsc::channel();
+    let probe = Arc::clone(probe);
+    thread::Builder::new()
+        .name(format!("day {day} {stage}"))
+        .spawn(move || {
+            probe.lock().unwrap().start(stage);
+            let result = panic::catch_unwind(AssertUnwindSafe(f));
+            probe.lock().unwrap().finish(stage);
+            // Nobody is listening any more if the stage timed out.
+            let _ = tx.send(result.unwrap_or_else(|p| Err(Error::panicked(p.as_ref()))));
+        })
+        .expect("couldn't spawn a thread");
+
+    let result = match timeout {
+        Some(limit) => rx
+            .recv_timeout(limit)
+            .unwrap_or_else(|_| Err(Error::timed_out(limit))),
+        // The thread always sends something, panic or not.
+        None => rx.recv().unwrap(),
+    };
+    result.map_err(|e| e.context(day, stage))
+}
+
+fn bench<S: for<'a> DaySolver<'a>>(
+    day: u8,
+    input: &str,
+    parts: Parts,
+    group: &mut BenchmarkGroup<'_, WallTime>,
+) -> Result<()> {
+    group.bench_with_input("parser", &input, |b, i| {
+        b.iter_with_large_drop(|| S::parse(i));
+    });
+
+    let parsed = S::parse(input).map_err(|e| e.context(day, Stage::Parse))?;
+
+    let benched = [
+        (
+            "part 1",
+            S::part1 as fn(&_) -> _,
+            S::part1_owned as fn(_) -> _,
+        ),
+        ("part 2", S::part2, S::part2_owned),
+    ];
+    for (i, &(id, borrowed, owned)) in benched.iter().enumerate() {
+        if !parts[i] {
+            continue;
+        }
+
+        group.bench_with_input(id, &parsed, |b, data| {
+            // Only parts that consume their input have to be handed a fresh copy each time.
+            if S::CONSUMES[i] {
+                b.iter_batched(|| data.clone(), owned, BatchSize::SmallInput);
+            } else {
+                b.iter(|| borrowed(data));
+            }
+        });
+    }
+
+    if S::SOLVES_BOTH && parts == [true, true] {
+        group.bench_with_input("both parts", &parsed, |b, data| {
+            b.iter(|| S::solve_both(data));
+        });
+    }
+
+    Ok(())
+}
+
+/// Every solver, ordered by year then day.
+pub fn all() -> &'static [Solver] {
+    static SOLVERS: OnceLock<Vec<Solver>> = OnceLock::new();
+
+    SOLVERS.get_or_init(|| YEARS.iter().flat_map(|solvers| solvers()).collect())
+}
+
+/// Looks up the solver for `day` of `year`.
+#[must_use]
+pub fn get(year: u16, day: u8) -> Option<&'static Solver> {
+    all().iter().find(|s| s.year == year && s.day == day)
+}
+
+/// The solvers the tests can afford to run. Slow days take too long without optimisations, so
+/// only `--release` checks them.
+#[cfg(test)]
+pub(crate) fn testable() -> impl Iterator<Item = &'static Solver> {
+    all().iter().filter(|s| !(cfg!(debug_assertions) && s.slow))
+}
+
+/// The path and trimmed contents of the puzzle input checked in for `day` of `year`. Days without
+/// one yet, or with just the empty file `new-day` leaves to paste it into, only have their examples
+/// to go on.
+#[cfg(test)]
+pub(crate) fn puzzle_input(year: u16, day: u8) -> Option<(String, String)> {
+    let path = format!("input/{year}/day{day}.txt");
+    let input = std::fs::read_to_string(&path).ok()?;
+    let input = input.trim();
+    (!input.is_empty()).then(|| (path, input.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::AnswerBook, error::ErrorKind};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn ordered_and_unique() {
+        assert!(all()
+            .windows(2)
+            .all(|w| (w[0].year, w[0].day) < (w[1].year, w[1].day)));
+    }
+
+    #[test]
+    fn backends() {
+        let day17 = get(2020, 17).unwrap();
+        for backend in Backend::ALL {
+            assert_eq!(
+                day17
+                    .with_backend(backend)
+                    .run(".#.\n..#\n###", [true, true], &mut ())
+                    .unwrap(),
+                [Some("112".to_string()), Some("848".to_string())],
+                "{backend:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn known_answers() {
+        for s in testable() {
+            let Some((path, input)) = puzzle_input(s.year, s.day) else {
+                continue;
+            };
+            let input = input.as_str();
+            let book =
+                AnswerBook::load(format!("input/{year}/answers.toml", year = s.year)).unwrap();
+            let entry = book.get(input).expect(&path);
+            let expected = [entry.part1.clone(), entry.part2.clone()];
+            let answers = s.run(input, [true, true], &mut ()).unwrap();
+            assert_eq!(answers, expected, "{path}");
+
+            if s.solves_both() {
+                let probe: SharedProbe = Arc::new(Mutex::new(()));
+                let input = Box::leak(input.to_string().into_boxed_str());
+                let outcomes = s.run_isolated(input, [true, true], None, &probe).unwrap();
+                assert_eq!(
+                    outcomes.both.unwrap().unwrap().map(Some),
+                    expected,
+                    "{path}"
+                );
+            }
+
+            if s.streams() {
+                let probe: SharedProbe = Arc::new(Mutex::new(()));
+                let file = std::io::BufReader::new(std::fs::File::open(&path).unwrap());
+                let outcomes = s
+                    .run_reader(Box::new(file), [true, true], None, &probe)
+                    .unwrap()
+                    .unwrap();
+                assert_eq!(outcomes.parts.map(|p| p.unwrap().ok()), expected, "{path}");
+            }
+        }
+    }
+
+    /// Panics in part 1 and never finishes part 2.
+    struct Broken;
+
+    impl<'a> DaySolver<'a> for Broken {
+        const YEAR: u16 = 2020;
+        const DAY: u8 = 1;
+
+        type Parsed = usize;
+        type Output = usize;
+
+        fn parse(input: &'a str) -> Result<Self::Parsed> {
+            Ok(input.len())
+        }
+
+        fn part1(_: &Self::Parsed) -> Result<Self::Output> {
+            panic!("oops")
+        }
+
+        fn part2(_: &Self::Parsed) -> Result<Self::Output> {
+            thread::sleep(Duration::MAX);
+            Ok(0)
+        }
+    }
+
+    #[test]
+    fn isolates_stages() {
+        let timeout = Duration::from_millis(100);
+        let probe: SharedProbe = Arc::new(Mutex::new(()));
+        let [part1, part2] = Solver::new::<Broken>()
+            .run_isolated("", [true, true], Some(timeout), &probe)
+            .unwrap()
+            .parts;
+
+        assert_eq!(
+            part1.unwrap().unwrap_err().to_string(),
+            "day 1 part 1: panicked: oops"
+        );
+        assert_eq!(
+            part2.unwrap().unwrap_err().kind(),
+            &ErrorKind::TimedOut(timeout)
+        );
+    }
+
+    static CLONES: AtomicUsize = AtomicUsize::new(0);
+
+    /// Counts how often it gets cloned.
+    struct Tracked;
+
+    impl Clone for Tracked {
+        fn clone(&self) -> Self {
+            CLONES.fetch_add(1, Ordering::SeqCst);
+            Self
+        }
+    }
+
+    /// Borrows in part 1 and consumes in part 2.
+    struct Consumer;
+
+    impl<'a> DaySolver<'a> for Consumer {
+        const YEAR: u16 = 2020;
+        const DAY: u8 = 2;
+
+        type Parsed = Tracked;
+        type Output = u8;
+
+        const CONSUMES: [bool; 2] = [false, true];
+
+        fn parse(_: &'a str) -> Result<Self::Parsed> {
+            Ok(Tracked)
+        }
+
+        fn part1(_: &Self::Parsed) -> Result<Self::Output> {
+            Ok(1)
+        }
+
+        fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+            Self::part2_owned(data.clone())
+        }
+
+        fn part2_owned(_: Self::Parsed) -> Result<Self::Output> {
+            Ok(2)
+        }
+    }
+
+    #[test]
+    fn hands_over_without_cloning() {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(1)
+            .build()
+            .unwrap();
+        let answers = pool
+            .install(|| Solver::new::<Consumer>().run("", [true, true], &mut ()))
+            .unwrap();
+
+        assert_eq!(answers, [Some("1".into()), Some("2".into())]);
+        assert_eq!(CLONES.load(Ordering::SeqCst), 0);
+    }
+}
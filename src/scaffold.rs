@@ -0,0 +1,327 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+const TEMPLATE: &str = include_str!("years/template.rs");
+
+/// What to generate for a new day.
+pub struct NewDay<'a> {
+    pub year: u16,
+    pub day: u8,
+    /// Example input from the puzzle text, which the stub tests parse.
+    pub example: Option<&'a str>,
+    /// What the example should give for each part. The stub outputs a `u64` or `i64` if they fit,
+    /// or a `String` otherwise.
+    pub answers: [Option<&'a str>; 2],
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Both answers as `T` literals, with a missing one as zero, if every given answer is a `T`.
+fn integer_answers<T: FromStr + Default + ToString>(
+    answers: [Option<&str>; 2],
+) -> Option<[String; 2]> {
+    let [part1, part2] = answers.map(|a| a.map_or(Some(T::default()), |a| a.parse().ok()));
+    Some([part1?.to_string(), part2?.to_string()])
+}
+
+impl NewDay<'_> {
+    /// Fills in the template for this day.
+    #[must_use]
+    pub fn render(&self) -> String {
+        let example = self.example.map_or_else(
+            || "\"\"".to_string(),
+            |e| {
+                format!(
+                    "\"{escaped}\"",
+                    escaped = e.trim().replace('\\', "\\\\").replace('"', "\\\"")
+                )
+            },
+        );
+        // The narrowest output type every answer fits in.
+        let answers = self.answers.map(|a| a.map(str::trim));
+        let (output, [part1, part2]) = if let Some(a) = integer_answers::<u64>(answers) {
+            ("u64", a)
+        } else if let Some(a) = integer_answers::<i64>(answers) {
+            ("i64", a)
+        } else {
+            let quoted = answers.map(|a| format!("{a:?}", a = a.unwrap_or_default()));
+            ("String", quoted)
+        };
+
+        TEMPLATE
+            .replace("YYYY", &self.year.to_string())
+            .replace("NUM", &self.day.to_string())
+            .replace("OUTPUT", output)
+            .replace("ANSWER1", &part1)
+            .replace("ANSWER2", &part2)
+            // Last, so nothing in the example gets mistaken for a placeholder.
+            .replace("EXAMPLE", &example)
+    }
+
+    /// Writes the new module under `src`, registers it, and creates an empty input under
+    /// `input_dir`. Returns every file that was created or changed.
+    ///
+    /// # Errors
+    ///
+    /// If the day already has a module, or a file can't be read or written.
+    pub fn generate(&self, src: &Path, input_dir: &Path) -> io::Result<Vec<PathBuf>> {
+        let year_dir = src.join(format!("years/y{year}", year = self.year));
+        let module = year_dir.join(format!("day{day}.rs", day = self.day));
+        if module.exists() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{module} already exists", module = module.display()),
+            ));
+        }
+
+        let mut touched = Vec::new();
+
+        let years = src.join("years/mod.rs");
+        if year_dir.exists() {
+            let path = year_dir.join("mod.rs");
+            let registered = self.register(&fs::read_to_string(&path)?)?;
+            fs::write(&path, registered)?;
+        } else {
+            fs::create_dir_all(&year_dir)?;
+            fs::write(year_dir.join("mod.rs"), self.new_year_module())?;
+            fs::write(&years, add_year(&fs::read_to_string(&years)?, self.year)?)?;
+            touched.push(years);
+        }
+        touched.push(year_dir.join("mod.rs"));
+
+        fs::write(&module, self.render())?;
+        touched.push(module);
+
+        let inputs = input_dir.join(self.year.to_string());
+        let input = inputs.join(format!("day{day}.txt", day = self.day));
+        if !input.exists() {
+            fs::create_dir_all(inputs)?;
+            fs::write(&input, "")?;
+            touched.push(input);
+        }
+
+        Ok(touched)
+    }
+
+    fn solver_line(&self) -> String {
+        format!(
+            "        Solver::new::<day{day}::Day{day}>(),",
+            day = self.day
+        )
+    }
+
+    fn new_year_module(&self) -> String {
+        format!("pub mod day{day};\n\nuse crate::registry::Solver;\n\npub fn solvers() -> Vec<Solver> {{\n    vec![\n{solver_line}\n    ]\n}}\n", day = self.day, solver_line = self.solver_line())
+    }
+
+    /// Adds the module declaration and the solver to an existing year's `mod.rs`.
+    fn register(&self, year_module: &str) -> io::Result<String> {
+        let mut lines: Vec<String> = year_module.lines().map(str::to_string).collect();
+
+        let module_line = format!("pub mod day{day};", day = self.day);
+        let first = lines.iter().position(|l| l.starts_with("pub mod day"));
+        let last = lines.iter().rposition(|l| l.starts_with("pub mod day"));
+        let (Some(first), Some(last)) = (first, last) else {
+            return Err(invalid("no `pub mod dayN;` lines to add to".to_string()));
+        };
+        let mut modules: Vec<_> = lines
+            .drain(first..=last)
+            .filter(|l| !l.is_empty())
+            .collect();
+        modules.push(module_line);
+        // The same order rustfmt keeps them in.
+        modules.sort_by_key(|l| l.trim_end_matches(';').to_string());
+        lines.splice(first..first, modules);
+
+        let day_of = |l: &str| {
+            l.trim_start()
+                .strip_prefix("Solver::")?
+                .split_once("::<day")?
+                .1
+                .split("::")
+                .next()?
+                .parse::<u8>()
+                .ok()
+        };
+        let close = lines
+            .iter()
+            .position(|l| l == "    ]")
+            .ok_or_else(|| invalid("no multi-line `vec![...]` in `solvers()`".to_string()))?;
+        let at = lines[..close]
+            .iter()
+            .position(|l| day_of(l).is_some_and(|d| d > self.day))
+            .unwrap_or(close);
+        lines.insert(at, self.solver_line());
+
+        let mut registered = lines.join("\n");
+        registered.push('\n');
+        Ok(registered)
+    }
+}
+
+/// Declares `year` in `years/mod.rs` and adds it to the list of events.
+fn add_year(years_module: &str, year: u16) -> io::Result<String> {
+    let mut years: Vec<u16> = years_module
+        .lines()
+        .filter_map(|l| l.strip_prefix("pub mod y")?.strip_suffix(';')?.parse().ok())
+        .collect();
+    years.push(year);
+    years.sort_unstable();
+
+    let modules: Vec<_> = years.iter().map(|y| format!("pub mod y{y};")).collect();
+    let solvers: Vec<_> = years.iter().map(|y| format!("y{y}::solvers")).collect();
+
+    let mut lines = Vec::new();
+    let mut declared = false;
+    for l in years_module.lines() {
+        if l.starts_with("pub mod y") {
+            if !declared {
+                lines.extend(modules.iter().cloned());
+                declared = true;
+            }
+        } else if l.starts_with("pub(crate) const YEARS") {
+            lines.push(format!(
+                "pub(crate) const YEARS: &[fn() -> Vec<Solver>] = &[{solvers}];",
+                solvers = solvers.join(", ")
+            ));
+        } else {
+            lines.push(l.to_string());
+        }
+    }
+
+    if !declared {
+        return Err(invalid("no `pub mod yNNNN;` lines to add to".to_string()));
+    }
+
+    let mut module = lines.join("\n");
+    module.push('\n');
+    Ok(module)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YEAR_MODULE: &str = "pub mod day1;
+pub mod day10;
+pub mod day2;
+
+use crate::registry::Solver;
+
+pub fn solvers() -> Vec<Solver> {
+    vec![
+        Solver::streaming::<day1::Day1>(),
+        Solver::new::<day2::Day2>().slow(),
+        Solver::new::<day10::Day10>()
+            .partial(),
+    ]
+}
+";
+
+    #[test]
+    fn registers_in_order() {
+        let day = NewDay {
+            year: 2020,
+            day: 3,
+            example: None,
+            answers: [None, None],
+        };
+
+        assert_eq!(
+            day.register(YEAR_MODULE).unwrap(),
+            "pub mod day1;
+pub mod day10;
+pub mod day2;
+pub mod day3;
+
+use crate::registry::Solver;
+
+pub fn solvers() -> Vec<Solver> {
+    vec![
+        Solver::streaming::<day1::Day1>(),
+        Solver::new::<day2::Day2>().slow(),
+        Solver::new::<day3::Day3>(),
+        Solver::new::<day10::Day10>()
+            .partial(),
+    ]
+}
+"
+        );
+    }
+
+    #[test]
+    fn renders_example_tests() {
+        let day = NewDay {
+            year: 2021,
+            day: 7,
+            example: Some("16,1,2\n\"x\"\n"),
+            answers: [Some("37"), None],
+        };
+        let module = day.render();
+
+        assert!(module.contains("pub struct Day7;"));
+        assert!(module.contains("const YEAR: u16 = 2021;"));
+        assert!(module.contains("const DAY: u8 = 7;"));
+        assert!(module.contains("fn d7p1()"));
+        assert!(module
+            .contains("Day7::part1(&Day7::parse(\"16,1,2\n\\\"x\\\"\").unwrap()).unwrap(), 37);"));
+        assert!(module
+            .contains("Day7::part2(&Day7::parse(\"16,1,2\n\\\"x\\\"\").unwrap()).unwrap(), 0);"));
+        assert!(module.contains("type Output = u64;"));
+    }
+
+    #[test]
+    fn picks_output_types() {
+        let render = |answers| {
+            NewDay {
+                year: 2021,
+                day: 7,
+                example: None,
+                answers,
+            }
+            .render()
+        };
+
+        let too_big = render([Some("-3"), Some("18446744073709551615")]);
+        assert!(too_big.contains("type Output = String;"));
+        assert!(too_big.contains(").unwrap(), \"-3\");"));
+
+        let negative = render([Some("-3"), Some(" 12 ")]);
+        assert!(negative.contains("type Output = i64;"));
+        assert!(negative.contains(").unwrap(), -3);"));
+        assert!(negative.contains(").unwrap(), 12);"));
+
+        let text = render([Some("1-2"), None]);
+        assert!(text.contains("type Output = String;"));
+        assert!(text.contains(").unwrap(), \"1-2\");"));
+        assert!(text.contains(").unwrap(), \"\");"));
+    }
+
+    const YEARS_MODULE: &str = "use crate::registry::Solver;
+
+pub mod y2020;
+
+/// Every event's solvers, oldest first.
+pub(crate) const YEARS: &[fn() -> Vec<Solver>] = &[y2020::solvers];
+";
+
+    #[test]
+    fn adds_years() {
+        assert_eq!(
+            add_year(YEARS_MODULE, 2019).unwrap(),
+            "use crate::registry::Solver;
+
+pub mod y2019;
+pub mod y2020;
+
+/// Every event's solvers, oldest first.
+pub(crate) const YEARS: &[fn() -> Vec<Solver>] = &[y2019::solvers, y2020::solvers];
+"
+        );
+    }
+}
@@ -1,13 +1,105 @@
+use std::io::BufRead;
+
+use crate::error::{Error, Result};
+
+#[allow(clippy::missing_errors_doc)]
 pub trait BStrParse {
     fn parse<F: lexical::FromLexical>(&self) -> Result<F, lexical::Error>;
+
+    /// Parses `self`, reporting failure as an [`Error`] located within `input`.
+    fn parse_in<F: lexical::FromLexical>(&self, input: impl AsRef<[u8]>) -> Result<F>;
 }
 
 impl BStrParse for [u8] {
     fn parse<F: lexical::FromLexical>(&self) -> Result<F, lexical::Error> {
         lexical::parse(self)
     }
+
+    fn parse_in<F: lexical::FromLexical>(&self, input: impl AsRef<[u8]>) -> Result<F> {
+        self.parse()
+            .map_err(|_| Error::parse(input, self, "a number"))
+    }
+}
+
+/// Hands `parse` each line of `reader` and its index, without the newline.
+///
+/// Lines are read through one reused buffer. Parse errors built against the line are moved to
+/// where it is in the whole input. Blank lines at the end are skipped, the same as trimming the
+/// input would.
+///
+/// # Errors
+///
+/// The first error from reading or from `parse`.
+pub fn for_each_line(
+    mut reader: impl BufRead,
+    mut parse: impl FnMut(usize, &[u8]) -> Result<()>,
+) -> Result<()> {
+    let mut line = Vec::new();
+    let mut index = 0;
+    let mut blanks = 0;
+
+    loop {
+        line.clear();
+        if reader
+            .read_until(b'\n', &mut line)
+            .map_err(|e| Error::read(&e))?
+            == 0
+        {
+            return Ok(());
+        }
+        if line.last() == Some(&b'\n') {
+            line.pop();
+        }
+
+        if line.is_empty() {
+            blanks += 1;
+            continue;
+        }
+        // Blank lines only get parsed once it's clear they aren't at the end.
+        for _ in 0..blanks {
+            parse(index, b"").map_err(|e| e.on_line(index + 1))?;
+            index += 1;
+        }
+        blanks = 0;
+
+        parse(index, &line).map_err(|e| e.on_line(index + 1))?;
+        index += 1;
+    }
 }
 
+/// A `(row, col)` position in a [`Grid`].
+pub type Point = (usize, usize);
+
+/// A `(row, col)` step from one [`Point`] to another.
+pub type Offset = (isize, isize);
+
+/// Up, left, right and down.
+pub const DIRECTIONS_4: [Offset; 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// [`DIRECTIONS_4`] and the diagonals between them, in reading order.
+pub const DIRECTIONS_8: [Offset; 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// Where a step off the side of a [`Grid`] ends up.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Edges {
+    /// Nowhere.
+    Stop,
+    /// Back in on the opposite side if it went off the left or right, nowhere otherwise.
+    WrapColumns,
+    /// Back in on the opposite side.
+    Wrap,
+}
+
+/// Rows of equal length, stored in one allocation. A grid with no columns has no rows either.
 #[derive(Clone)]
 pub struct Grid<T> {
     data: Vec<T>,
@@ -15,6 +107,8 @@ pub struct Grid<T> {
     line_count: usize,
 }
 
+/// Panics if the rows aren't all the same length. Use [`Grid::try_from_rows`] for input that
+/// might be ragged.
 impl<T, I2: Iterator<Item = T>> std::iter::FromIterator<I2> for Grid<T> {
     #[inline]
     fn from_iter<I: IntoIterator<Item = I2>>(iter: I) -> Self {
@@ -23,16 +117,28 @@ impl<T, I2: Iterator<Item = T>> std::iter::FromIterator<I2> for Grid<T> {
         let (low, high) = iter.size_hint();
         let mut data = Vec::with_capacity(high.map_or(low, |x| x));
 
-        data.extend(iter.next().unwrap());
+        let mut line_count = if let Some(first) = iter.next() {
+            data.extend(first);
+            1
+        } else {
+            0
+        };
         let line_length = data.len();
 
         // This is significantly faster than data.extend(i.flatten()). Dunno why.
         for i in iter {
             data.extend(i);
+            line_count += 1;
+            assert!(
+                data.len() == line_length * line_count,
+                "row {line_count} of the grid isn't {line_length} long",
+                line_count = line_count - 1
+            );
         }
 
-        debug_assert!(data.len() % line_length == 0);
-        let line_count = data.len() / line_length;
+        if line_length == 0 {
+            line_count = 0;
+        }
 
         Self {
             data,
@@ -58,9 +164,26 @@ impl<T> std::ops::IndexMut<usize> for Grid<T> {
     }
 }
 
+impl<T> std::ops::Index<Point> for Grid<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, (row, col): Point) -> &Self::Output {
+        &self[row][col]
+    }
+}
+
+impl<T> std::ops::IndexMut<Point> for Grid<T> {
+    #[inline]
+    fn index_mut(&mut self, (row, col): Point) -> &mut Self::Output {
+        &mut self[row][col]
+    }
+}
+
 impl<T: Clone> Grid<T> {
     #[inline]
     pub fn from_value(value: T, line_length: usize, line_count: usize) -> Self {
+        let line_count = if line_length == 0 { 0 } else { line_count };
         Self {
             line_length,
             line_count,
@@ -70,7 +193,47 @@ impl<T: Clone> Grid<T> {
 }
 
 impl<T> Grid<T> {
+    /// Like `collect`, but stops at the first element that failed to parse, and builds an error
+    /// with `ragged` from the index of the first row that isn't as long as the first one.
+    ///
+    /// # Errors
+    ///
+    /// The first element's error, or the one from `ragged`.
+    pub fn try_from_rows<E, I, R>(rows: I, ragged: impl FnOnce(usize) -> E) -> Result<Self, E>
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = Result<T, E>>,
+    {
+        let mut data = Vec::new();
+        let mut line_length = 0;
+        let mut line_count = 0;
+
+        for row in rows {
+            for x in row {
+                data.push(x?);
+            }
+
+            line_count += 1;
+            if line_count == 1 {
+                line_length = data.len();
+            } else if data.len() != line_length * line_count {
+                return Err(ragged(line_count - 1));
+            }
+        }
+
+        if line_length == 0 {
+            line_count = 0;
+        }
+
+        Ok(Self {
+            data,
+            line_length,
+            line_count,
+        })
+    }
+
     #[inline]
+    #[must_use]
     pub fn get(&self, i: usize) -> Option<&[T]> {
         if i < self.line_count {
             let start = i * self.line_length;
@@ -92,7 +255,7 @@ impl<T> Grid<T> {
 
     #[inline]
     pub fn iter(&self) -> impl Iterator<Item = &'_ [T]> {
-        self.data.chunks_exact(self.line_length)
+        self.data.chunks_exact(self.line_length.max(1))
     }
 
     #[inline]
@@ -102,7 +265,7 @@ impl<T> Grid<T> {
 
     #[inline]
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &'_ mut [T]> {
-        self.data.chunks_exact_mut(self.line_length)
+        self.data.chunks_exact_mut(self.line_length.max(1))
     }
 
     #[inline]
@@ -111,15 +274,106 @@ impl<T> Grid<T> {
     }
 
     #[inline]
+    #[must_use]
     pub fn len(&self) -> usize {
         self.line_count
     }
 
     #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.line_count == 0
+    }
+
+    #[inline]
+    #[must_use]
     pub fn line_length(&self) -> usize {
         self.line_length
     }
 
+    #[inline]
+    #[must_use]
+    pub fn at(&self, (row, col): Point) -> Option<&T> {
+        self.get(row)?.get(col)
+    }
+
+    #[inline]
+    pub fn at_mut(&mut self, (row, col): Point) -> Option<&mut T> {
+        self.get_mut(row)?.get_mut(col)
+    }
+
+    /// Every point in the grid, in reading order.
+    #[inline]
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let line_length = self.line_length;
+        (0..self.line_count).flat_map(move |row| (0..line_length).map(move |col| (row, col)))
+    }
+
+    /// Where `by` away from `from` is, if that's in the grid. `from` has to be in it.
+    #[inline]
+    #[must_use]
+    pub fn step(&self, (row, col): Point, (dr, dc): Offset, edges: Edges) -> Option<Point> {
+        let stop = |x: usize, d, len| x.checked_add_signed(d).filter(|&x| x < len);
+        let wrap =
+            |x: usize, d: isize, len: usize| (x as isize + d).rem_euclid(len as isize) as usize;
+
+        match edges {
+            Edges::Stop => Some((
+                stop(row, dr, self.line_count)?,
+                stop(col, dc, self.line_length)?,
+            )),
+            Edges::WrapColumns => Some((
+                stop(row, dr, self.line_count)?,
+                wrap(col, dc, self.line_length),
+            )),
+            Edges::Wrap => Some((
+                wrap(row, dr, self.line_count),
+                wrap(col, dc, self.line_length),
+            )),
+        }
+    }
+
+    /// The points next to `p` up, down, left and right.
+    #[inline]
+    pub fn neighbors_4(&self, p: Point, edges: Edges) -> impl Iterator<Item = Point> + '_ {
+        DIRECTIONS_4
+            .iter()
+            .filter_map(move |&by| self.step(p, by, edges))
+    }
+
+    /// The points next to `p`, diagonals included.
+    #[inline]
+    pub fn neighbors_8(&self, p: Point, edges: Edges) -> impl Iterator<Item = Point> + '_ {
+        DIRECTIONS_8
+            .iter()
+            .filter_map(move |&by| self.step(p, by, edges))
+    }
+
+    /// The points passed going from `from` in steps of `by`, not counting `from` itself. Ends at
+    /// the edge of the grid, or on getting back to `from` when wrapping around.
+    #[inline]
+    pub fn ray(&self, from: Point, by: Offset, edges: Edges) -> impl Iterator<Item = Point> + '_ {
+        let mut at = Some(from);
+        std::iter::from_fn(move || {
+            at = at
+                .and_then(|p| self.step(p, by, edges))
+                .filter(|&p| p != from);
+            at
+        })
+    }
+
+    /// The first point along [`Self::ray`] whose value `found` holds for.
+    #[inline]
+    pub fn cast(
+        &self,
+        from: Point,
+        by: Offset,
+        edges: Edges,
+        mut found: impl FnMut(&T) -> bool,
+    ) -> Option<Point> {
+        self.ray(from, by, edges).find(|&p| found(&self[p]))
+    }
+
     #[inline]
     pub fn retain<F>(&mut self, mut f: F)
     where
@@ -157,3 +411,202 @@ impl<T> Grid<T> {
         self.line_count = len;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::{collection::vec, prelude::*};
+    use std::panic;
+
+    /// Between no and a few rows, all with the same number of columns.
+    fn rows() -> impl Strategy<Value = Vec<Vec<u8>>> {
+        (1..8_usize, 0..8_usize)
+            .prop_flat_map(|(width, height)| vec(vec(any::<u8>(), width), height))
+    }
+
+    /// Rows where one of them other than the first is a different length.
+    fn ragged() -> impl Strategy<Value = (Vec<Vec<u8>>, usize)> {
+        (rows(), any::<prop::sample::Index>(), 1..4_usize)
+            .prop_filter("needs a second row", |(r, ..)| r.len() > 1)
+            .prop_map(|(mut r, row, by)| {
+                let row = row.index(r.len() - 1) + 1;
+                if row.is_multiple_of(2) {
+                    r[row].extend(std::iter::repeat_n(0, by));
+                } else {
+                    let len = r[row].len();
+                    r[row].truncate(len.saturating_sub(by));
+                }
+                (r, row)
+            })
+    }
+
+    fn collect(model: &[Vec<u8>]) -> Grid<u8> {
+        model.iter().map(|r| r.iter().copied()).collect()
+    }
+
+    fn check(grid: &Grid<u8>, model: &[Vec<u8>]) -> Result<(), TestCaseError> {
+        prop_assert_eq!(grid.len(), model.len());
+        if let Some(first) = model.first() {
+            prop_assert_eq!(grid.line_length(), first.len());
+        }
+        for i in 0..=model.len() {
+            prop_assert_eq!(grid.get(i), model.get(i).map(Vec::as_slice));
+        }
+        for (i, row) in model.iter().enumerate() {
+            prop_assert_eq!(&grid[i], row.as_slice());
+        }
+        prop_assert!(grid.iter().eq(model.iter().map(Vec::as_slice)));
+        prop_assert!(grid.flat_iter().eq(model.iter().flatten()));
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn collects(model in rows()) {
+            check(&collect(&model), &model)?;
+        }
+
+        #[test]
+        fn tries_rows(model in rows(), bad in any::<u8>()) {
+            let grid = Grid::try_from_rows(
+                model
+                    .iter()
+                    .map(|r| r.iter().map(|&x| if x == bad { Err(x) } else { Ok(x) })),
+                |_| unreachable!(),
+            );
+
+            if model.iter().flatten().any(|&x| x == bad) {
+                prop_assert_eq!(grid.err(), Some(bad));
+            } else {
+                check(&grid.unwrap(), &model)?;
+            }
+        }
+
+        #[test]
+        fn rejects_ragged_rows((model, row) in ragged()) {
+            let grid = Grid::try_from_rows(model.iter().map(|r| r.iter().map(|&x| Ok(x))), |i| i);
+            prop_assert_eq!(grid.err(), Some(row));
+
+            prop_assert!(panic::catch_unwind(|| collect(&model)).is_err());
+        }
+
+        #[test]
+        fn from_value(value in any::<u8>(), width in 1..8_usize, height in 0..8_usize) {
+            check(&Grid::from_value(value, width, height), &vec![vec![value; width]; height])?;
+        }
+
+        #[test]
+        fn retains(mut model in rows(), modulus in 1..4_u8) {
+            let keep = |r: &[u8]| r[0].is_multiple_of(modulus);
+            let mut grid = collect(&model);
+
+            grid.retain(keep);
+            model.retain(|r| keep(r));
+            check(&grid, &model)?;
+        }
+
+        #[test]
+        fn mutates(mut model in rows(), ops in vec((0..3_u8, any::<usize>(), any::<usize>(), any::<u8>()), 0..16)) {
+            let mut grid = collect(&model);
+
+            for (op, i, j, value) in ops {
+                if model.is_empty() {
+                    break;
+                }
+                let (i, j) = (i % model.len(), j % model[0].len());
+                match op {
+                    0 => {
+                        grid[i][j] = value;
+                        model[i][j] = value;
+                    }
+                    1 => {
+                        for (g, m) in grid.iter_mut().zip(&mut model) {
+                            g[j] = g[j].wrapping_add(value);
+                            m[j] = m[j].wrapping_add(value);
+                        }
+                    }
+                    _ => {
+                        grid.flat_iter_mut().for_each(|x| *x ^= value);
+                        model.iter_mut().flatten().for_each(|x| *x ^= value);
+                    }
+                }
+                check(&grid, &model)?;
+            }
+        }
+    }
+
+    /// 3 rows of 4, numbered in reading order.
+    fn numbered() -> Grid<u8> {
+        (0..3).map(|r| (0..4).map(move |c| r * 4 + c)).collect()
+    }
+
+    #[test]
+    fn points() {
+        let grid = numbered();
+        assert_eq!(grid[(1, 2)], 6);
+        assert_eq!(grid.at((2, 3)), Some(&11));
+        assert_eq!(grid.at((3, 0)), None);
+        assert_eq!(grid.at((0, 4)), None);
+        assert!(grid.points().map(|p| grid[p]).eq(0..12));
+    }
+
+    #[test]
+    fn steps() {
+        let grid = numbered();
+        assert_eq!(grid.step((0, 0), (1, 2), Edges::Stop), Some((1, 2)));
+        assert_eq!(grid.step((0, 0), (-1, 0), Edges::Stop), None);
+        assert_eq!(grid.step((0, 3), (0, 1), Edges::Stop), None);
+        assert_eq!(grid.step((0, 3), (0, 1), Edges::WrapColumns), Some((0, 0)));
+        assert_eq!(grid.step((0, 0), (-1, -5), Edges::WrapColumns), None);
+        assert_eq!(grid.step((0, 0), (-1, -5), Edges::Wrap), Some((2, 3)));
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = numbered();
+        let values = |ps: &mut dyn Iterator<Item = Point>| ps.map(|p| grid[p]).collect::<Vec<_>>();
+
+        assert_eq!(
+            values(&mut grid.neighbors_4((1, 1), Edges::Stop)),
+            [1, 4, 6, 9]
+        );
+        assert_eq!(values(&mut grid.neighbors_4((0, 0), Edges::Stop)), [1, 4]);
+        assert_eq!(
+            values(&mut grid.neighbors_8((0, 0), Edges::Stop)),
+            [1, 4, 5]
+        );
+        assert_eq!(
+            values(&mut grid.neighbors_8((0, 0), Edges::Wrap)),
+            [11, 8, 9, 3, 1, 7, 4, 5]
+        );
+    }
+
+    #[test]
+    fn rays() {
+        let grid = numbered();
+        assert!(grid.ray((0, 0), (1, 1), Edges::Stop).eq([(1, 1), (2, 2)]));
+        assert!(grid
+            .ray((0, 0), (1, 3), Edges::WrapColumns)
+            .eq([(1, 3), (2, 2)]));
+        assert_eq!(grid.ray((1, 1), (0, 1), Edges::Wrap).count(), 3);
+        assert_eq!(grid.ray((1, 1), (0, 0), Edges::Wrap).count(), 0);
+
+        assert_eq!(
+            grid.cast((0, 0), (0, 1), Edges::Stop, |&v| v > 1),
+            Some((0, 2))
+        );
+        assert_eq!(grid.cast((0, 0), (0, 1), Edges::Stop, |&v| v > 3), None);
+        assert_eq!(
+            grid.cast((0, 2), (0, 1), Edges::Wrap, |&v| v < 2),
+            Some((0, 0))
+        );
+    }
+
+    #[test]
+    fn no_columns_means_no_rows() {
+        let grid: Grid<u8> = vec![vec![]; 3].into_iter().map(Vec::into_iter).collect();
+        assert_eq!(grid.len(), 0);
+        assert_eq!(grid.iter().count(), 0);
+        assert_eq!(Grid::from_value(0_u8, 0, 3).len(), 0);
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{day_solver::DaySolver, error::Result};
+
+pub struct DayNUM;
+
+impl DaySolver<'_> for DayNUM {
+    const YEAR: u16 = YYYY;
+    const DAY: u8 = NUM;
+
+    type Parsed = u32;
+    type Output = OUTPUT;
+
+    fn parse(_input: &str) -> Result<Self::Parsed> {
+        todo!()
+    }
+
+    fn part1(_data: &Self::Parsed) -> Result<Self::Output> {
+        todo!()
+    }
+
+    fn part2(_data: &Self::Parsed) -> Result<Self::Output> {
+        todo!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dNUMp1() {
+        assert_eq!(DayNUM::part1(&DayNUM::parse(EXAMPLE).unwrap()).unwrap(), ANSWER1);
+    }
+
+    #[test]
+    fn dNUMp2() {
+        assert_eq!(DayNUM::part2(&DayNUM::parse(EXAMPLE).unwrap()).unwrap(), ANSWER2);
+    }
+}
@@ -0,0 +1,111 @@
+use std::io::BufRead;
+
+use crate::{
+    day_solver::{DaySolver, StreamingDaySolver},
+    error::{Error, Result},
+    util::*,
+};
+
+pub struct Day1;
+
+impl DaySolver<'_> for Day1 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 1;
+
+    type Parsed = Vec<Self::Output>;
+    type Output = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        let mut res: Self::Parsed = input
+            .as_bytes()
+            .split(|&x| x == b'\n')
+            .map(|x| x.parse_in(input))
+            .collect::<Result<_>>()?;
+        res.sort_unstable();
+        Ok(res)
+    }
+
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        for (i, x) in data.iter().enumerate() {
+            if let Some(y) = 2020_u32.checked_sub(*x) {
+                if data[i + 1..].binary_search(&y).is_ok() {
+                    return x
+                        .checked_mul(y)
+                        .ok_or_else(|| Error::no_solution("the entries multiply past u32"));
+                }
+            }
+        }
+        Err(Error::no_solution("no two entries sum to 2020"))
+    }
+
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        for (i, x) in data.iter().enumerate() {
+            for (j, y) in data[i + 1..].iter().enumerate() {
+                if let Some(z) = x.checked_add(*y).and_then(|s| 2020_u32.checked_sub(s)) {
+                    if data[i + j + 2..].binary_search(&z).is_ok() {
+                        return x
+                            .checked_mul(*y)
+                            .and_then(|p| p.checked_mul(z))
+                            .ok_or_else(|| Error::no_solution("the entries multiply past u32"));
+                    }
+                }
+            }
+        }
+        Err(Error::no_solution("no three entries sum to 2020"))
+    }
+}
+
+impl StreamingDaySolver<'_> for Day1 {
+    fn parse_reader(reader: impl BufRead) -> Result<Self::Parsed> {
+        let mut res = Vec::new();
+        for_each_line(reader, |_, line| {
+            res.push(line.parse_in(line)?);
+            Ok(())
+        })?;
+        res.sort_unstable();
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn d1p1() {
+        assert_eq!(
+            Day1::part1(
+                &Day1::parse(
+                    "1721
+979
+366
+299
+675
+1456"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            1721 * 299
+        );
+    }
+
+    #[test]
+    fn d1p2() {
+        assert_eq!(
+            Day1::part2(
+                &Day1::parse(
+                    "1721
+979
+366
+299
+675
+1456"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            979 * 366 * 675
+        );
+    }
+}
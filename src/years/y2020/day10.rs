@@ -0,0 +1,216 @@
+use crate::{
+    day_solver::DaySolver,
+    error::{Error, Result},
+    util::*,
+};
+
+pub struct Day10;
+
+type N = u16;
+
+impl DaySolver<'_> for Day10 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 10;
+
+    type Parsed = Vec<N>;
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        let mut jolts: Self::Parsed = input
+            .as_bytes()
+            .split(|&x| x == b'\n')
+            .map(|x| x.parse_in(input))
+            .chain(std::iter::once(Ok(0)))
+            .collect::<Result<_>>()?;
+        jolts.sort_unstable();
+        let device = jolts
+            .last()
+            .unwrap()
+            .checked_add(3)
+            .ok_or_else(|| Error::no_solution("the device's joltage doesn't fit in u16"))?;
+        jolts.push(device);
+
+        Ok(jolts)
+    }
+
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        let mut diffs = [0; 4];
+
+        for w in data.windows(2) {
+            *diffs
+                .get_mut((w[1] - w[0]) as usize)
+                .ok_or_else(|| Error::no_solution("adapters more than 3 jolts apart"))? += 1;
+        }
+
+        Ok(diffs[1] * diffs[3])
+    }
+
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        // vec![0] is special cased and faster than non-zero. Since we only need the first spot to be 1, do this instead of vec![1].
+        let mut solutions = vec![0; data.len()];
+        solutions[0] = 1;
+
+        for i in 1..data.len() {
+            let mut sum: u64 = 0;
+            for j in i.saturating_sub(3)..i {
+                if data[i] - data[j] <= 3 {
+                    sum = sum
+                        .checked_add(solutions[j])
+                        .ok_or_else(|| Error::no_solution("the arrangements count past u64"))?;
+                }
+            }
+            solutions[i] = sum;
+        }
+
+        Ok(*solutions.last().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn d10p1() {
+        assert_eq!(
+            Day10::part1(
+                &Day10::parse(
+                    "16
+10
+15
+5
+1
+11
+7
+19
+6
+12
+4"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            7 * 5
+        );
+
+        assert_eq!(
+            Day10::part1(
+                &Day10::parse(
+                    "28
+33
+18
+42
+31
+14
+46
+20
+48
+47
+24
+23
+49
+45
+19
+38
+39
+11
+1
+32
+25
+35
+8
+17
+7
+9
+4
+2
+34
+10
+3"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            22 * 10
+        );
+    }
+
+    #[test]
+    fn d10p2() {
+        assert_eq!(
+            Day10::part2(
+                &Day10::parse(
+                    "16
+10
+15
+5
+1
+11
+7
+19
+6
+12
+4"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            8
+        );
+
+        assert_eq!(
+            Day10::part2(
+                &Day10::parse(
+                    "28
+33
+18
+42
+31
+14
+46
+20
+48
+47
+24
+23
+49
+45
+19
+38
+39
+11
+1
+32
+25
+35
+8
+17
+7
+9
+4
+2
+34
+10
+3"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            19208
+        );
+    }
+
+    #[test]
+    fn overflow() {
+        assert!(matches!(
+            Day10::parse("65535").err().unwrap().kind(),
+            crate::error::ErrorKind::NoSolution(_)
+        ));
+
+        let duplicates = Day10::parse(&["1"; 100].join("\n")).unwrap();
+        assert!(matches!(
+            Day10::part2(&duplicates).unwrap_err().kind(),
+            crate::error::ErrorKind::NoSolution(_)
+        ));
+    }
+}
@@ -0,0 +1,542 @@
+use std::fmt;
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    day_solver::DaySolver,
+    error::{Error, Result},
+    util::*,
+};
+
+pub struct Day11;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Position {
+    Floor,
+    Empty,
+    Occupied,
+}
+
+use Position::*;
+
+impl DaySolver<'_> for Day11 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 11;
+
+    type Parsed = Grid<Position>;
+    type Output = usize;
+
+    const CONSUMES: [bool; 2] = [true, true];
+    const SOLVES_BOTH: bool = true;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        let input = input.as_bytes();
+        if let Some(i) = input
+            .iter()
+            .position(|&c| !matches!(c, b'.' | b'L' | b'#' | b'\n'))
+        {
+            return Err(Error::parse(input, &input[i..=i], "`.`, `L` or `#`"));
+        }
+        if input.first().is_none_or(|&c| c == b'\n') {
+            return Err(Error::parse(input, &input[..0], "a row of seats"));
+        }
+
+        let lines = || input.split(|&x| x == b'\n');
+        Grid::try_from_rows(
+            lines().map(|l| {
+                l.iter().map(|c| {
+                    Ok(match c {
+                        b'.' => Floor,
+                        b'L' => Empty,
+                        _ => Occupied,
+                    })
+                })
+            }),
+            |row| {
+                Error::parse(
+                    input,
+                    lines().nth(row).unwrap(),
+                    "a row as long as the first",
+                )
+            },
+        )
+    }
+
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        Self::part1_owned(data.clone())
+    }
+
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        Self::part2_owned(data.clone())
+    }
+
+    fn part1_owned(data: Self::Parsed) -> Result<Self::Output> {
+        occupied_when_settled(Seating::new(data, &adjacent, 4))
+    }
+
+    fn part2_owned(data: Self::Parsed) -> Result<Self::Output> {
+        occupied_when_settled(Seating::new(data, &visible, 5))
+    }
+
+    fn solve_both(data: &Self::Parsed) -> Result<(Self::Output, Self::Output)> {
+        let (near, far) = Seating::for_both_parts(data.clone());
+        Ok((occupied_when_settled(near)?, occupied_when_settled(far)?))
+    }
+}
+
+fn occupied_when_settled(mut seating: Seating) -> Result<usize> {
+    match seating.run_until_stable() {
+        Settled::Fixed { .. } => Ok(seating.occupied()),
+        Settled::Cycle { .. } => Err(Error::no_solution("the seats never settle")),
+    }
+}
+
+/// Which seats a seat pays attention to when deciding whether to change.
+pub trait Visibility {
+    /// Calls `see` with each seat that the seat at `p` pays attention to.
+    fn look(&self, seats: &Grid<Position>, p: Point, see: impl FnMut(Point));
+}
+
+/// Something that finds the seat a seat pays attention to in direction `by`, if any, looks in
+/// each of the eight directions.
+impl<F: Fn(&Grid<Position>, Point, Offset) -> Option<Point>> Visibility for F {
+    fn look(&self, seats: &Grid<Position>, p: Point, mut see: impl FnMut(Point)) {
+        for &by in &DIRECTIONS_8 {
+            if let Some(seat) = self(seats, p, by) {
+                see(seat);
+            }
+        }
+    }
+}
+
+/// The spot right next to `p` in direction `by`, as in part 1.
+#[must_use]
+pub fn adjacent(seats: &Grid<Position>, p: Point, by: Offset) -> Option<Point> {
+    seats.step(p, by, Edges::Stop)
+}
+
+/// The first seat in direction `by` from `p`, looking past floor, as in part 2.
+#[must_use]
+pub fn visible(seats: &Grid<Position>, p: Point, by: Offset) -> Option<Point> {
+    seats.cast(p, by, Edges::Stop, |d| !matches!(d, Floor))
+}
+
+/// How a [`Seating`] ended up once it stopped doing anything new.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Settled {
+    /// Nothing changes after `generation`.
+    Fixed { generation: usize },
+    /// Generation `start` comes back every `period` generations.
+    Cycle { start: usize, period: usize },
+}
+
+/// People filling and leaving seats.
+///
+/// Everyone moves at once each generation. An empty seat gets taken if none of the seats it pays
+/// attention to are occupied, and an occupied one is left if at least `threshold` of them are.
+#[derive(Clone)]
+pub struct Seating {
+    seats: Grid<Position>,
+    /// The flat indices of the seats paying attention to each seat, from `starts[i]` up to
+    /// `starts[i + 1]` for seat `i`, so an occupied seat can tell them all it's taken.
+    watchers: Vec<u32>,
+    starts: Vec<u32>,
+    threshold: u32,
+    /// Which seats are occupied, one bit each, which is all that changes.
+    occupied: Vec<u64>,
+    counts: Vec<u32>,
+    generation: usize,
+}
+
+impl Seating {
+    pub fn new(seats: Grid<Position>, visibility: &impl Visibility, threshold: u32) -> Self {
+        let mut neighbors = Vec::new();
+        let mut starts = Vec::with_capacity(seats.len() * seats.line_length() + 1);
+        starts.push(0);
+
+        for p in seats.points() {
+            if !matches!(seats[p], Floor) {
+                visibility.look(&seats, p, |(row, col)| {
+                    neighbors.push((row * seats.line_length() + col) as u32);
+                });
+            }
+            starts.push(neighbors.len() as u32);
+        }
+
+        Self::from_neighbors(seats, &neighbors, &starts, threshold)
+    }
+
+    /// The seatings for part 1 and part 2, from one walk over the seats. Whenever the spot next
+    /// to a seat is a seat too, that's also the one it sees, so only floor needs looking past.
+    fn for_both_parts(seats: Grid<Position>) -> (Self, Self) {
+        let len = seats.len() * seats.line_length() + 1;
+        let (mut near, mut near_starts) = (Vec::new(), Vec::with_capacity(len));
+        let (mut far, mut far_starts) = (Vec::new(), Vec::with_capacity(len));
+        near_starts.push(0);
+        far_starts.push(0);
+        let flat = |(row, col): Point| (row * seats.line_length() + col) as u32;
+
+        for p in seats.points() {
+            if !matches!(seats[p], Floor) {
+                for &by in &DIRECTIONS_8 {
+                    let Some(next) = adjacent(&seats, p, by) else {
+                        continue;
+                    };
+                    near.push(flat(next));
+                    if !matches!(seats[next], Floor) {
+                        far.push(flat(next));
+                    } else if let Some(seen) = visible(&seats, next, by) {
+                        far.push(flat(seen));
+                    }
+                }
+            }
+            near_starts.push(near.len() as u32);
+            far_starts.push(far.len() as u32);
+        }
+
+        (
+            Self::from_neighbors(seats.clone(), &near, &near_starts, 4),
+            Self::from_neighbors(seats, &far, &far_starts, 5),
+        )
+    }
+
+    /// Takes the flat indices of the seats each seat pays attention to, laid out like
+    /// [`Self::watchers`].
+    fn from_neighbors(
+        seats: Grid<Position>,
+        neighbors: &[u32],
+        starts: &[u32],
+        threshold: u32,
+    ) -> Self {
+        let (watchers, starts) = transpose(neighbors, starts);
+
+        let mut occupied = vec![0; (seats.len() * seats.line_length()).div_ceil(64)];
+        for (i, seat) in seats.flat_iter().enumerate() {
+            if matches!(seat, Occupied) {
+                occupied[i / 64] |= 1 << (i % 64);
+            }
+        }
+
+        Self {
+            occupied,
+            counts: vec![0; starts.len() - 1],
+            seats,
+            watchers,
+            starts,
+            threshold,
+            generation: 0,
+        }
+    }
+
+    #[must_use]
+    pub fn seats(&self) -> &Grid<Position> {
+        &self.seats
+    }
+
+    /// How many steps have been taken.
+    #[must_use]
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    #[must_use]
+    pub fn occupied(&self) -> usize {
+        self.occupied.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Moves everyone once. Returns whether anything changed.
+    pub fn step(&mut self) -> bool {
+        self.counts.fill(0);
+        for (w, &word) in self.occupied.iter().enumerate() {
+            let mut word = word;
+            while word != 0 {
+                let i = w * 64 + word.trailing_zeros() as usize;
+                word &= word - 1;
+                for &n in &self.watchers[self.starts[i] as usize..self.starts[i + 1] as usize] {
+                    self.counts[n as usize] += 1;
+                }
+            }
+        }
+
+        let mut change = false;
+        for (i, (seat, &c)) in self.seats.flat_iter_mut().zip(&self.counts).enumerate() {
+            *seat = match seat {
+                Empty if c == 0 => Occupied,
+                Occupied if c >= self.threshold => Empty,
+                _ => continue,
+            };
+            self.occupied[i / 64] ^= 1 << (i % 64);
+            change = true;
+        }
+
+        self.generation += 1;
+        change
+    }
+
+    /// Steps until nothing changes, or until the seats flip back and forth between two
+    /// generations, keeping nothing more than the generation before last around.
+    ///
+    /// When every seat pays attention to the seats that pay attention to it, as with
+    /// [`adjacent`] and [`visible`], those are the only ways it can end up. Otherwise it could
+    /// go round a longer cycle forever, and [`settle`](Self::settle) is the one to use.
+    pub fn run_until_stable(&mut self) -> Settled {
+        let mut before_last = Vec::with_capacity(self.occupied.len());
+        loop {
+            before_last.clone_from(&self.occupied);
+            if !self.step() || !self.step() {
+                return Settled::Fixed {
+                    generation: self.generation - 1,
+                };
+            }
+            if self.occupied == before_last {
+                return Settled::Cycle {
+                    start: self.generation - 2,
+                    period: 2,
+                };
+            }
+        }
+    }
+
+    /// Steps until nothing changes, or an earlier generation comes back, which means keeping
+    /// every generation around to compare against.
+    pub fn settle(&mut self) -> Settled {
+        let mut seen = FxHashMap::default();
+        seen.insert(self.occupied.clone(), self.generation);
+
+        loop {
+            if !self.step() {
+                return Settled::Fixed {
+                    generation: self.generation - 1,
+                };
+            }
+            if let Some(start) = seen.insert(self.occupied.clone(), self.generation) {
+                return Settled::Cycle {
+                    start,
+                    period: self.generation - start,
+                };
+            }
+        }
+    }
+}
+
+/// Turns lists of which seats each seat pays attention to, laid out like
+/// [`Seating::watchers`], around into lists of which seats pay attention to each seat.
+fn transpose(neighbors: &[u32], starts: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    let mut watcher_starts = vec![0; starts.len()];
+    for &n in neighbors {
+        watcher_starts[n as usize + 1] += 1;
+    }
+    for i in 1..watcher_starts.len() {
+        watcher_starts[i] += watcher_starts[i - 1];
+    }
+
+    let mut watchers = vec![0; neighbors.len()];
+    let mut next = watcher_starts.clone();
+    for (seat, w) in starts.windows(2).enumerate() {
+        for &n in &neighbors[w[0] as usize..w[1] as usize] {
+            watchers[next[n as usize] as usize] = seat as u32;
+            next[n as usize] += 1;
+        }
+    }
+
+    (watchers, watcher_starts)
+}
+
+/// The seats in the same form as the input.
+impl fmt::Display for Seating {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.seats.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for seat in row {
+                let c = match seat {
+                    Floor => '.',
+                    Empty => 'L',
+                    Occupied => '#',
+                };
+                write!(f, "{c}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn d11p1() {
+        assert_eq!(
+            Day11::part1(
+                &Day11::parse(
+                    "L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            37
+        );
+    }
+
+    #[test]
+    fn d11p2() {
+        assert_eq!(
+            Day11::part2(
+                &Day11::parse(
+                    "L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            26
+        );
+    }
+
+    #[test]
+    fn d11both() {
+        assert_eq!(
+            Day11::solve_both(
+                &Day11::parse(
+                    "L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            (37, 26)
+        );
+    }
+
+    #[test]
+    fn steps() {
+        let example = "L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL";
+        let mut seating = Seating::new(Day11::parse(example).unwrap(), &adjacent, 4);
+        assert_eq!(seating.to_string(), example);
+
+        assert!(seating.step());
+        assert!(seating.step());
+        assert_eq!(seating.generation(), 2);
+        assert_eq!(
+            seating.to_string(),
+            "#.LL.L#.##
+#LLLLLL.L#
+L.L.L..L..
+#LLL.LL.L#
+#.LL.LL.LL
+#.LLLL#.##
+..L.L.....
+#LLLLLLLL#
+#.LLLLLL.L
+#.#LLLL.##"
+        );
+
+        assert_eq!(seating.settle(), Settled::Fixed { generation: 5 });
+        assert_eq!(seating.occupied(), 37);
+        assert!(!seating.step());
+    }
+
+    #[test]
+    fn cycles() {
+        // Seats that all see each other fill up and empty out in turn.
+        let mut seating = Seating::new(Day11::parse("L.L\n...\nL.L").unwrap(), &visible, 1);
+        assert_eq!(
+            seating.settle(),
+            Settled::Cycle {
+                start: 0,
+                period: 2
+            }
+        );
+        assert_eq!(seating.to_string(), "L.L\n...\nL.L");
+        assert_eq!(
+            seating.clone().run_until_stable(),
+            Settled::Cycle {
+                start: 2,
+                period: 2
+            }
+        );
+        assert_eq!(
+            occupied_when_settled(seating).unwrap_err().kind(),
+            &crate::error::ErrorKind::NoSolution("the seats never settle")
+        );
+    }
+
+    /// Only looks along the row.
+    struct Row;
+
+    impl Visibility for Row {
+        fn look(&self, seats: &Grid<Position>, p: Point, mut see: impl FnMut(Point)) {
+            for by in [(0, -1), (0, 1)] {
+                if let Some(seat) = visible(seats, p, by) {
+                    see(seat);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn custom() {
+        let mut seating = Seating::new(Day11::parse("LLL\nL.L").unwrap(), &Row, 2);
+        assert_eq!(seating.settle(), Settled::Fixed { generation: 2 });
+        assert_eq!(seating.to_string(), "#L#\n#.#");
+    }
+
+    /// Only looks at the next seat to the right.
+    struct Right;
+
+    impl Visibility for Right {
+        fn look(&self, seats: &Grid<Position>, p: Point, mut see: impl FnMut(Point)) {
+            if let Some(seat) = visible(seats, p, (0, 1)) {
+                see(seat);
+            }
+        }
+    }
+
+    #[test]
+    fn one_way() {
+        // The left seat watches the right one, which watches nothing, so only the left one
+        // gets crowded out.
+        let mut seating = Seating::new(Day11::parse("LL").unwrap(), &Right, 1);
+        assert!(seating.step());
+        assert_eq!(seating.to_string(), "##");
+        assert!(seating.step());
+        assert_eq!(seating.to_string(), "L#");
+        assert_eq!(seating.settle(), Settled::Fixed { generation: 2 });
+    }
+}
@@ -0,0 +1,241 @@
+use std::{io::BufRead, mem::swap};
+
+use crate::{
+    day_solver::{DaySolver, StreamingDaySolver},
+    error::{Error, Result},
+    util::*,
+};
+
+pub struct Day12;
+
+#[derive(Copy, Clone)]
+pub enum Order {
+    North,
+    South,
+    East,
+    West,
+    Left,
+    Right,
+    Forward,
+}
+
+#[derive(Copy, Clone)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+type N = i64;
+
+#[derive(Copy, Clone)]
+pub struct Move {
+    order: Order,
+    count: N,
+}
+
+impl DaySolver<'_> for Day12 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 12;
+
+    type Parsed = Vec<Move>;
+    type Output = N;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input
+            .as_bytes()
+            .split(|&x| x == b'\n')
+            .map(|l| parse_move(input.as_bytes(), l))
+            .collect()
+    }
+
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        let mut current_direction = Direction::East;
+        let mut x = 0;
+        let mut y = 0;
+
+        for m in data {
+            match (current_direction, m.order) {
+                (Direction::North, Order::Forward) | (_, Order::North) => y = add(y, m.count)?,
+                (Direction::South, Order::Forward) | (_, Order::South) => y = sub(y, m.count)?,
+                (Direction::East, Order::Forward) | (_, Order::East) => x = add(x, m.count)?,
+                (Direction::West, Order::Forward) | (_, Order::West) => x = sub(x, m.count)?,
+                (_, Order::Left) => {
+                    for _ in 0..quarter_turns(m.count) {
+                        current_direction = match current_direction {
+                            Direction::North => Direction::West,
+                            Direction::South => Direction::East,
+                            Direction::East => Direction::North,
+                            Direction::West => Direction::South,
+                        }
+                    }
+                }
+                (_, Order::Right) => {
+                    for _ in 0..quarter_turns(m.count) {
+                        current_direction = match current_direction {
+                            Direction::North => Direction::East,
+                            Direction::South => Direction::West,
+                            Direction::East => Direction::South,
+                            Direction::West => Direction::North,
+                        }
+                    }
+                }
+            }
+        }
+
+        manhattan(x, y)
+    }
+
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        let mut x = 0;
+        let mut y = 0;
+        let mut waypoint_x = 10;
+        let mut waypoint_y = 1;
+
+        for m in data {
+            match m.order {
+                Order::North => waypoint_y = add(waypoint_y, m.count)?,
+                Order::South => waypoint_y = sub(waypoint_y, m.count)?,
+                Order::East => waypoint_x = add(waypoint_x, m.count)?,
+                Order::West => waypoint_x = sub(waypoint_x, m.count)?,
+                Order::Left => {
+                    for _ in 0..quarter_turns(m.count) {
+                        swap(&mut waypoint_x, &mut waypoint_y);
+                        waypoint_x *= -1;
+                    }
+                }
+                Order::Right => {
+                    for _ in 0..quarter_turns(m.count) {
+                        swap(&mut waypoint_x, &mut waypoint_y);
+                        waypoint_y *= -1;
+                    }
+                }
+                Order::Forward => {
+                    x = add(x, mul(waypoint_x, m.count)?)?;
+                    y = add(y, mul(waypoint_y, m.count)?)?;
+                }
+            }
+        }
+
+        manhattan(x, y)
+    }
+}
+
+impl StreamingDaySolver<'_> for Day12 {
+    fn parse_reader(reader: impl BufRead) -> Result<Self::Parsed> {
+        let mut res = Vec::new();
+        for_each_line(reader, |_, line| {
+            res.push(parse_move(line, line)?);
+            Ok(())
+        })?;
+        Ok(res)
+    }
+}
+
+/// Turning all the way round changes nothing, so only the last few quarter turns are made.
+fn quarter_turns(degrees: N) -> N {
+    degrees / 90 % 4
+}
+
+fn too_far() -> Error {
+    Error::no_solution("the ship sails too far to keep track of")
+}
+
+fn add(a: N, b: N) -> Result<N> {
+    a.checked_add(b).ok_or_else(too_far)
+}
+
+fn sub(a: N, b: N) -> Result<N> {
+    a.checked_sub(b).ok_or_else(too_far)
+}
+
+fn mul(a: N, b: N) -> Result<N> {
+    a.checked_mul(b).ok_or_else(too_far)
+}
+
+fn manhattan(x: N, y: N) -> Result<N> {
+    add(
+        x.checked_abs().ok_or_else(too_far)?,
+        y.checked_abs().ok_or_else(too_far)?,
+    )
+}
+
+fn parse_move(input: &[u8], l: &[u8]) -> Result<Move> {
+    Ok(Move {
+        order: match l.first() {
+            Some(b'N') => Order::North,
+            Some(b'S') => Order::South,
+            Some(b'E') => Order::East,
+            Some(b'W') => Order::West,
+            Some(b'F') => Order::Forward,
+            Some(b'L') => Order::Left,
+            Some(b'R') => Order::Right,
+            _ => return Err(Error::parse(input, l, "a navigation action")),
+        },
+        count: l[1..].parse_in(input)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn d12p1() {
+        assert_eq!(
+            Day12::part1(
+                &Day12::parse(
+                    "F10
+N3
+F7
+R90
+F11"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            25
+        );
+    }
+
+    #[test]
+    fn d12p2() {
+        assert_eq!(
+            Day12::part2(
+                &Day12::parse(
+                    "F10
+N3
+F7
+R90
+F11"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            286
+        );
+    }
+
+    #[test]
+    fn far_out() {
+        // Further than an i32 reaches, but well within an i64.
+        let input = "F2000000000\nF2000000000";
+        assert_eq!(
+            Day12::part1(&Day12::parse(input).unwrap()).unwrap(),
+            4_000_000_000
+        );
+        assert_eq!(
+            Day12::part2(&Day12::parse(input).unwrap()).unwrap(),
+            44_000_000_000
+        );
+
+        let input = "E4000000000000000000\nF4000000000000000000";
+        assert!(matches!(
+            Day12::part2(&Day12::parse(input).unwrap())
+                .unwrap_err()
+                .kind(),
+            crate::error::ErrorKind::NoSolution(_)
+        ));
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{
+    day_solver::DaySolver,
+    error::{Error, Result},
+    util::*,
+};
+
+pub struct Day13;
+
+type N = u64;
+
+impl DaySolver<'_> for Day13 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 13;
+
+    type Parsed = (N, Vec<Option<N>>);
+    type Output = N;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        let input = input.as_bytes();
+        let linebreak =
+            memchr::memchr(b'\n', input).ok_or_else(|| Error::eof(input, "a list of bus ids"))?;
+
+        Ok((
+            input[0..linebreak].parse_in(input)?,
+            input[linebreak + 1..]
+                .split(|&x| x == b',')
+                .map(|x| {
+                    if x == b"x" {
+                        Ok(None)
+                    } else {
+                        match x.parse_in(input)? {
+                            0 => Err(Error::parse(input, x, "a non-zero bus id")),
+                            id => Ok(Some(id)),
+                        }
+                    }
+                })
+                .collect::<Result<_>>()?,
+        ))
+    }
+
+    fn part1(&(timestamp, ref ids): &Self::Parsed) -> Result<Self::Output> {
+        let mut min = N::MAX;
+        let mut min_id = N::MAX;
+        for &id in ids.iter().flatten() {
+            let rem = (id - timestamp % id) % id;
+            if rem < min {
+                min = rem;
+                min_id = id;
+            }
+        }
+
+        if min_id == N::MAX {
+            return Err(Error::no_solution("no buses in service"));
+        }
+
+        Ok(min_id * min)
+    }
+
+    fn part2((_, ids): &Self::Parsed) -> Result<Self::Output> {
+        let too_late = || Error::no_solution("the timestamp is past u64");
+        let mut previous = 1;
+        // `None` once it no longer fits, which only matters if another bus comes after.
+        let mut products = Some(1);
+        for (i, &id) in ids.iter().enumerate() {
+            let i = i as N;
+            if let Some(id) = id {
+                let step = products.ok_or_else(too_late)?;
+                let want = (id - (i % id)) % id;
+                // The residues repeat after `id` steps, so give up if none matched by then.
+                let mut tries = 1;
+                while previous % id != want {
+                    if tries == id {
+                        return Err(Error::no_solution("bus ids aren't coprime"));
+                    }
+                    previous = previous.checked_add(step).ok_or_else(too_late)?;
+                    tries += 1;
+                }
+                products = step.checked_mul(id);
+            }
+        }
+
+        Ok(previous)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn d13p1() {
+        assert_eq!(
+            Day13::part1(
+                &Day13::parse(
+                    "939
+7,13,x,x,59,x,31,19"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            295
+        );
+    }
+
+    #[test]
+    fn d13p2() {
+        assert_eq!(
+            Day13::part2(
+                &Day13::parse(
+                    "939
+7,13,x,x,59,x,31,19"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            1_068_781
+        );
+    }
+
+    #[test]
+    fn past_u64() {
+        assert!(matches!(
+            Day13::part2(
+                &Day13::parse("0\n2,3,5,7,11,13,17,19,23,29,31,37,41,43,47,53,59").unwrap()
+            )
+            .unwrap_err()
+            .kind(),
+            crate::error::ErrorKind::NoSolution("the timestamp is past u64")
+        ));
+    }
+}
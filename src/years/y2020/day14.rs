@@ -0,0 +1,330 @@
+use std::io::BufRead;
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    day_solver::{DaySolver, StreamingDaySolver},
+    error::{Error, Result},
+    util::*,
+};
+
+pub struct Day14;
+
+/// Each address with this many floating bits gets written to 2^20 times in part 2. Past that,
+/// part 2 keeps track of which addresses each write still holds instead.
+const MAX_FLOATING: u32 = 20;
+
+/// Don't reserve more than this up front for part 2, however many addresses it might touch.
+const MAX_CAPACITY: usize = 1 << 20;
+
+#[derive(Copy, Clone)]
+pub enum Op {
+    Mask(u64, u64, u64),
+    Assign(u64, u64),
+}
+
+use Op::*;
+
+impl DaySolver<'_> for Day14 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 14;
+
+    type Parsed = (Vec<Op>, usize);
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        let mut counts = Counts::default();
+        let res = input
+            .as_bytes()
+            .split(|&x| x == b'\n')
+            .map(|line| parse_op(input.as_bytes(), line, &mut counts))
+            .collect::<Result<_>>()?;
+        Ok((res, counts.part2_size()))
+    }
+
+    fn part1((data, _): &Self::Parsed) -> Result<Self::Output> {
+        let mut current_or_mask = 0;
+        let mut current_and_mask = u64::MAX;
+        let mut memory = FxHashMap::with_capacity_and_hasher(data.len(), Default::default());
+
+        for &x in data {
+            match x {
+                Mask(or_mask, and_mask, _) => {
+                    current_or_mask = or_mask;
+                    current_and_mask = and_mask;
+                }
+                Assign(index, num) => {
+                    *memory.entry(index).or_default() = (num & current_and_mask) | current_or_mask;
+                }
+            }
+        }
+
+        sum_memory(&memory)
+    }
+
+    fn part2(&(ref data, size): &Self::Parsed) -> Result<Self::Output> {
+        if data
+            .iter()
+            .any(|op| matches!(op, Mask(_, _, x) if x.count_ones() > MAX_FLOATING))
+        {
+            return part2_without_expanding(data);
+        }
+
+        let mut current_or_mask = 0;
+        let mut current_x_mask = 0;
+        let mut memory = FxHashMap::with_capacity_and_hasher(size, Default::default());
+
+        for &x in data {
+            match x {
+                Mask(or_mask, _, x_mask) => {
+                    current_or_mask = or_mask;
+                    current_x_mask = x_mask;
+                }
+                Assign(index, num) => {
+                    let floating = current_x_mask.count_ones();
+                    let index = index | current_or_mask;
+                    let mut xor = current_x_mask + 1;
+
+                    for _ in 0..1_u64 << floating {
+                        xor = (xor - 1) & current_x_mask;
+                        *memory.entry(index ^ xor).or_default() = num;
+                    }
+                }
+            }
+        }
+
+        sum_memory(&memory)
+    }
+}
+
+fn sum_memory(memory: &FxHashMap<u64, u64>) -> Result<u64> {
+    memory
+        .values()
+        .try_fold(0_u64, |sum, &v| sum.checked_add(v))
+        .ok_or_else(|| Error::no_solution("the sum of memory doesn't fit in 64 bits"))
+}
+
+/// Addresses that match `base` everywhere but the bits in `floating`, which can be anything.
+/// `base` is 0 at those bits.
+#[derive(Copy, Clone)]
+struct Pattern {
+    base: u64,
+    floating: u64,
+}
+
+impl Pattern {
+    fn overlaps(self, other: Self) -> bool {
+        (self.base ^ other.base) & !(self.floating | other.floating) == 0
+    }
+
+    /// Calls `keep` with patterns that between them hold just the addresses in `self` but not
+    /// in `other`, without any address in two of them.
+    fn remove(mut self, other: Self, mut keep: impl FnMut(Self)) {
+        if !self.overlaps(other) {
+            keep(self);
+            return;
+        }
+        let mut split = self.floating & !other.floating;
+        while split != 0 {
+            let bit = split & split.wrapping_neg();
+            split ^= bit;
+            self.floating ^= bit;
+            // Where `bit` differs from `other`, nothing further down can overlap it.
+            keep(Self {
+                base: self.base | (!other.base & bit),
+                ..self
+            });
+            self.base |= other.base & bit;
+        }
+        // What's left is all inside `other`.
+    }
+}
+
+/// Part 2 for masks with too many floating bits to write every address they make. Each value
+/// is kept with the addresses it's still in, which later writes cut down.
+fn part2_without_expanding(data: &[Op]) -> Result<u64> {
+    let mut or_mask = 0;
+    let mut x_mask = 0;
+    let mut writes: Vec<(Pattern, u64)> = Vec::new();
+    let mut next = Vec::new();
+
+    for &op in data {
+        match op {
+            Mask(or, _, x) => {
+                or_mask = or;
+                x_mask = x;
+            }
+            Assign(index, num) => {
+                let write = Pattern {
+                    base: (index | or_mask) & !x_mask,
+                    floating: x_mask,
+                };
+                next.clear();
+                for &(pattern, value) in &writes {
+                    pattern.remove(write, |p| next.push((p, value)));
+                }
+                next.push((write, num));
+                std::mem::swap(&mut writes, &mut next);
+            }
+        }
+    }
+
+    writes
+        .iter()
+        .try_fold(0_u64, |sum, &(pattern, value)| {
+            value
+                .checked_shl(pattern.floating.count_ones())
+                .filter(|v| v >> pattern.floating.count_ones() == value)
+                .and_then(|v| sum.checked_add(v))
+        })
+        .ok_or_else(|| Error::no_solution("the sum of memory doesn't fit in 64 bits"))
+}
+
+impl StreamingDaySolver<'_> for Day14 {
+    fn parse_reader(reader: impl BufRead) -> Result<Self::Parsed> {
+        let mut counts = Counts::default();
+        let mut res = Vec::new();
+        for_each_line(reader, |_, line| {
+            res.push(parse_op(line, line, &mut counts)?);
+            Ok(())
+        })?;
+        Ok((res, counts.part2_size()))
+    }
+}
+
+/// What's needed to guess how much memory part 2 ends up writing to.
+#[derive(Default)]
+struct Counts {
+    x: u32,
+    assign: usize,
+    mask: u32,
+}
+
+impl Counts {
+    fn part2_size(&self) -> usize {
+        let per_assign = 1_usize
+            .checked_shl((self.x / self.mask.max(1)) + 1)
+            .unwrap_or(usize::MAX);
+        self.assign.saturating_mul(per_assign).min(MAX_CAPACITY)
+    }
+}
+
+fn parse_op(input: &[u8], line: &[u8], counts: &mut Counts) -> Result<Op> {
+    match line.get(0..4) {
+        Some(b"mask") => {
+            counts.mask += 1;
+            let mut or_mask = 0;
+            let mut and_mask = u64::MAX;
+            let mut x_mask = 0;
+
+            let bits = match line.get(7..) {
+                Some(bits) if line[4..7] == *b" = " && bits.len() == 36 => bits,
+                _ => return Err(Error::parse(input, line, "`mask = ` and 36 bits")),
+            };
+
+            for (i, bit) in bits.iter().enumerate() {
+                match bit {
+                    b'0' => {
+                        or_mask <<= 1;
+                        and_mask <<= 1;
+                        x_mask <<= 1;
+                    }
+                    b'1' => {
+                        or_mask = (or_mask << 1) + 1;
+                        and_mask = (and_mask << 1) + 1;
+                        x_mask <<= 1;
+                    }
+                    b'X' => {
+                        or_mask <<= 1;
+                        and_mask = (and_mask << 1) + 1;
+                        x_mask = (x_mask << 1) + 1;
+                        counts.x += 1;
+                    }
+                    _ => return Err(Error::parse(input, &bits[i..=i], "`0`, `1` or `X`")),
+                }
+            }
+            Ok(Mask(or_mask, and_mask, x_mask))
+        }
+        Some(b"mem[") => {
+            counts.assign += 1;
+            let close_bracket = line
+                .iter()
+                .position(|&x| x == b']')
+                .ok_or_else(|| Error::parse(input, line, "`]`"))?;
+            let index = line[4..close_bracket].parse_in(input)?;
+            let num = match line.get(close_bracket..close_bracket + 4) {
+                Some(b"] = ") => line[close_bracket + 4..].parse_in(input)?,
+                _ => return Err(Error::parse(input, &line[close_bracket..], "`] = `")),
+            };
+            Ok(Assign(index, num))
+        }
+        _ => Err(Error::parse(input, line, "`mask` or `mem[`")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn d14p1() {
+        assert_eq!(
+            Day14::part1(
+                &Day14::parse(
+                    "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
+mem[8] = 11
+mem[7] = 101
+mem[8] = 0"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            165
+        );
+    }
+
+    #[test]
+    fn d14p2() {
+        assert_eq!(
+            Day14::part2(
+                &Day14::parse(
+                    "mask = 000000000000000000000000000000X1001X
+mem[42] = 100
+mask = 00000000000000000000000000000000X0XX
+mem[26] = 1"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            208
+        );
+    }
+
+    #[test]
+    fn too_many_floating() {
+        let data = Day14::parse("mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX\nmem[0] = 1").unwrap();
+        assert_eq!(Day14::part2(&data).unwrap(), 1 << 36);
+        assert_eq!(Day14::part1(&data).unwrap(), 1);
+
+        // Later writes take their addresses away from earlier ones.
+        let data = Day14::parse(
+            "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX
+mem[0] = 3
+mask = 0000000000000000000000000000000000X1
+mem[0] = 5
+mask = 000000000000000000000000000000000000
+mem[1] = 7",
+        )
+        .unwrap();
+        assert_eq!(Day14::part2(&data).unwrap(), 3 * ((1 << 36) - 2) + 5 + 7);
+
+        let (data, _) = Day14::parse(
+            "mask = 000000000000000000000000000000X1001X
+mem[42] = 100
+mask = 00000000000000000000000000000000X0XX
+mem[26] = 1",
+        )
+        .unwrap();
+        assert_eq!(part2_without_expanding(&data).unwrap(), 208);
+    }
+}
@@ -0,0 +1,116 @@
+use std::num::NonZeroU32;
+
+use crate::{
+    day_solver::DaySolver,
+    error::{Error, Result},
+    util::*,
+};
+
+pub struct Day15;
+
+type N = u32;
+type NZ = NonZeroU32;
+
+/// Part 2's turn count. Nothing spoken after the starting numbers can be this big, and
+/// bounding the starting numbers by it too keeps the turn table from outgrowing part 2's.
+const PART2_TURNS: N = 30_000_000;
+
+impl DaySolver<'_> for Day15 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 15;
+
+    type Parsed = Vec<N>;
+    type Output = N;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input
+            .as_bytes()
+            .split(|&x| x == b',')
+            .map(|x| match x.parse_in(input)? {
+                n if n < PART2_TURNS => Ok(n),
+                _ => Err(Error::parse(input, x, "a starting number below 30000000")),
+            })
+            .collect()
+    }
+
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        run(data, 2020)
+    }
+
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        run(data, PART2_TURNS)
+    }
+}
+
+fn run(data: &<Day15 as DaySolver>::Parsed, count: N) -> Result<N> {
+    let size = data.iter().map(|&x| x + 1).fold(count, std::cmp::max);
+    let mut map: Vec<Option<NZ>> = vec![None; size as usize];
+
+    for (&x, i) in data.iter().zip(1..) {
+        map[x as usize] = NZ::new(i);
+    }
+
+    let mut last = *data
+        .last()
+        .ok_or_else(|| Error::no_solution("no starting numbers"))?;
+
+    for turn in data.len() as N..count {
+        let nz_turn = NZ::new(turn);
+        let prev = std::mem::replace(&mut map[last as usize], nz_turn);
+        last = match prev {
+            Some(v) => nz_turn.unwrap().get() - v.get(),
+            None => 0,
+        };
+    }
+
+    Ok(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn d15p1() {
+        assert_eq!(Day15::part1(&Day15::parse("0,3,6").unwrap()).unwrap(), 436);
+        assert_eq!(Day15::part1(&Day15::parse("1,3,2").unwrap()).unwrap(), 1);
+        assert_eq!(Day15::part1(&Day15::parse("2,1,3").unwrap()).unwrap(), 10);
+        assert_eq!(Day15::part1(&Day15::parse("1,2,3").unwrap()).unwrap(), 27);
+        assert_eq!(Day15::part1(&Day15::parse("2,3,1").unwrap()).unwrap(), 78);
+        assert_eq!(Day15::part1(&Day15::parse("3,2,1").unwrap()).unwrap(), 438);
+        assert_eq!(Day15::part1(&Day15::parse("3,1,2").unwrap()).unwrap(), 1836);
+    }
+
+    #[test]
+    fn d15p2() {
+        assert_eq!(
+            Day15::part2(&Day15::parse("0,3,6").unwrap()).unwrap(),
+            175_594
+        );
+        assert_eq!(Day15::part2(&Day15::parse("1,3,2").unwrap()).unwrap(), 2578);
+        assert_eq!(
+            Day15::part2(&Day15::parse("2,1,3").unwrap()).unwrap(),
+            3_544_142
+        );
+        assert_eq!(
+            Day15::part2(&Day15::parse("1,2,3").unwrap()).unwrap(),
+            261_214
+        );
+        assert_eq!(
+            Day15::part2(&Day15::parse("2,3,1").unwrap()).unwrap(),
+            6_895_259
+        );
+        assert_eq!(Day15::part2(&Day15::parse("3,2,1").unwrap()).unwrap(), 18);
+        assert_eq!(Day15::part2(&Day15::parse("3,1,2").unwrap()).unwrap(), 362);
+    }
+
+    #[test]
+    fn huge_start() {
+        assert!(Day15::parse("4000000000,0").is_err());
+        assert!(Day15::parse("30000000,0").is_err());
+        assert_eq!(
+            Day15::part1(&Day15::parse("29999999,0").unwrap()).unwrap(),
+            104
+        );
+    }
+}
@@ -0,0 +1,203 @@
+use std::ops::RangeInclusive;
+
+use serde_scan::scan;
+
+use crate::{
+    day_solver::DaySolver,
+    error::{Error, Result},
+    util::*,
+};
+
+pub struct Day16;
+
+type N = u16;
+
+#[derive(Clone, Debug)]
+pub struct Rule<'a> {
+    name: &'a [u8],
+    ranges: [RangeInclusive<N>; 2],
+}
+
+impl<'a> DaySolver<'a> for Day16 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 16;
+
+    type Parsed = (Vec<Rule<'a>>, Vec<N>, Grid<N>);
+    type Output = u64;
+
+    const CONSUMES: [bool; 2] = [false, true];
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        let mut lines = input.lines();
+        let mut rules = Vec::new();
+
+        for l in lines.by_ref() {
+            if l.is_empty() {
+                break;
+            }
+
+            let (name, min1, max1, min2, max2) = scan!("{}: {}-{} or {}-{}" <- l)
+                .map_err(|_| Error::parse(input, l.as_bytes(), "a field rule"))?;
+            rules.push(Rule {
+                name,
+                ranges: [min1..=max1, min2..=max2],
+            });
+        }
+
+        lines.next(); // your ticket:
+        let mine = lines
+            .next()
+            .ok_or_else(|| Error::eof(input, "your ticket"))?
+            .as_bytes()
+            .split(|&x| x == b',')
+            .map(|x| x.parse_in(input))
+            .collect::<Result<_>>()?;
+
+        lines.nth(1); // nearby tickets:
+        let mut rows = lines.clone();
+        let mut lines = lines.peekable();
+        if lines.peek().is_none() {
+            return Err(Error::eof(input, "nearby tickets"));
+        }
+
+        let tickets = Grid::try_from_rows(
+            lines.map(|l| {
+                l.as_bytes()
+                    .split(|&x| x == b',')
+                    .map(|x| x.parse_in(input))
+            }),
+            |row| {
+                let line = rows.nth(row).unwrap();
+                Error::parse(input, line.as_bytes(), "as many values as the first ticket")
+            },
+        )?;
+
+        Ok((rules, mine, tickets))
+    }
+
+    fn part1((rules, _, tickets): &Self::Parsed) -> Result<Self::Output> {
+        let mut sum = 0;
+
+        for t in tickets.iter() {
+            if let Some(v) = is_valid_ticket(t, rules) {
+                sum += v;
+            }
+        }
+
+        Ok(sum as u64)
+    }
+
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        Self::part2_owned(data.clone())
+    }
+
+    fn part2_owned((rules, mine, mut tickets): Self::Parsed) -> Result<Self::Output> {
+        let ambiguous = || Error::no_solution("fields can't be matched to positions");
+        let ticket_length = tickets.line_length() as N;
+
+        tickets.retain(|t| is_valid_ticket(t, &rules).is_none());
+
+        let mut valid_positions = vec![(0..ticket_length).collect::<Vec<_>>(); rules.len()];
+
+        for t in tickets.iter() {
+            for (vi, v) in t.iter().enumerate() {
+                for (ri, r) in rules.iter().enumerate() {
+                    if !r.ranges.iter().any(|r2| r2.contains(v)) {
+                        remove_item(&mut valid_positions[ri], vi as N);
+                    }
+                }
+            }
+        }
+
+        let mut results: Vec<_> = rules.iter().zip(valid_positions.iter_mut()).collect();
+        results.sort_unstable_by_key(|v| v.1.len());
+
+        for i in 0..results.len() {
+            let [v] = results[i].1[..] else {
+                return Err(ambiguous());
+            };
+
+            for j in &mut results[i + 1..] {
+                remove_item(j.1, v);
+            }
+        }
+
+        results
+            .into_iter()
+            .filter(|(r, _)| r.name.starts_with(b"departure"))
+            .map(|(_, v)| {
+                mine.get(v[0] as usize)
+                    .map(|&x| x as u64)
+                    .ok_or_else(ambiguous)
+            })
+            .product()
+    }
+}
+
+fn is_valid_ticket(t: &[N], rules: &[Rule]) -> Option<N> {
+    t.iter().copied().find(|v| {
+        !rules
+            .iter()
+            .any(|r| r.ranges.iter().any(|r2| r2.contains(v)))
+    })
+}
+
+fn remove_item(r: &mut Vec<N>, v: N) {
+    if let Some(i) = r.iter().position(|&x| x == v) {
+        r.swap_remove(i);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn d16p1() {
+        assert_eq!(
+            Day16::part1(
+                &Day16::parse(
+                    "class: 1-3 or 5-7
+row: 6-11 or 33-44
+seat: 13-40 or 45-50
+
+your ticket:
+7,1,14
+
+nearby tickets:
+7,3,47
+40,4,50
+55,2,20
+38,6,12"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            71
+        );
+    }
+
+    #[test]
+    fn d16p2() {
+        assert_eq!(
+            Day16::part2(
+                &Day16::parse(
+                    "class: 0-1 or 4-19
+departure row: 0-5 or 8-19
+seat: 0-13 or 16-19
+
+your ticket:
+11,12,13
+
+nearby tickets:
+3,9,18
+15,1,5
+5,14,9"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            11
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use std::{convert::TryFrom, marker::PhantomData};
+
+use rustc_hash::FxHashSet;
+
+use crate::{
+    automaton::{pick, Automaton, Backend, Cell, Coord, Pick, LIFE},
+    day_solver::DaySolver,
+    error::{Error, Result},
+    registry::Solver,
+};
+
+/// Day 17, with its pocket kept track of by the backend `B` picks.
+pub struct Day17<B = pick::Auto>(PhantomData<B>);
+
+// Mirrored along the extra axes because the input starts flat at 0 on them.
+const POCKET3: Automaton<3, 26> = Automaton::new(LIFE).mirrored(1);
+const POCKET4: Automaton<4, 80> = Automaton::new(LIFE).mirrored(2);
+
+impl<B: Pick> DaySolver<'_> for Day17<B> {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 17;
+
+    type Parsed = FxHashSet<Cell<3>>;
+    type Output = usize;
+
+    const CONSUMES: [bool; 2] = [true, false];
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        let input = input.as_bytes();
+        if let Some(i) = input
+            .iter()
+            .position(|&c| !matches!(c, b'.' | b'#' | b'\n'))
+        {
+            return Err(Error::parse(input, &input[i..=i], "`.` or `#`"));
+        }
+
+        // Too far along for a coordinate to hold.
+        let coord = |i: usize, at: &[u8]| {
+            Coord::try_from(i).map_err(|_| Error::parse(input, at, "a smaller slice"))
+        };
+
+        input
+            .split(|&x| x == b'\n')
+            .enumerate()
+            .flat_map(|(y, l)| {
+                l.iter()
+                    .enumerate()
+                    .filter(|&(_, &c)| c == b'#')
+                    .map(move |(x, _)| Ok([coord(x, &l[x..=x])?, coord(y, &l[x..=x])?, 0]))
+            })
+            .collect()
+    }
+
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        Self::part1_owned(data.clone())
+    }
+
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        Ok(run4(data, B::BACKEND))
+    }
+
+    fn part1_owned(data: Self::Parsed) -> Result<Self::Output> {
+        Ok(POCKET3.backend(B::BACKEND).run(data))
+    }
+}
+
+/// Day 17's solver for `backend`.
+#[must_use]
+pub fn with_backend(backend: Backend) -> Solver {
+    match backend {
+        Backend::Sparse => Solver::new::<Day17<pick::Sparse>>(),
+        Backend::Dense => Solver::new::<Day17<pick::Dense>>(),
+        Backend::Auto => Solver::new::<Day17>(),
+    }
+}
+
+fn run4(data: &<Day17 as DaySolver>::Parsed, backend: Backend) -> usize {
+    let data = data.iter().map(|&[x, y, z]| [x, y, z, 0]).collect();
+    POCKET4.backend(backend).run(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn d17p1() {
+        assert_eq!(
+            <Day17>::part1(
+                &<Day17>::parse(
+                    ".#.
+..#
+###"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            112
+        );
+    }
+
+    #[test]
+    fn d17p2() {
+        assert_eq!(
+            <Day17>::part2(
+                &<Day17>::parse(
+                    ".#.
+..#
+###"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            848
+        );
+    }
+
+    #[test]
+    fn every_backend() {
+        let input = std::fs::read_to_string("input/2020/day17.txt").unwrap();
+        let book = crate::answers::AnswerBook::load("input/2020/answers.toml").unwrap();
+        let entry = book.get(input.trim()).unwrap();
+        let data = <Day17>::parse(input.trim()).unwrap();
+
+        for &backend in &Backend::ALL {
+            let part1 = POCKET3.backend(backend).run(data.clone());
+            let part2 = run4(&data, backend);
+            assert_eq!(entry.get(1), Some(&*part1.to_string()), "{backend:?}");
+            assert_eq!(entry.get(2), Some(&*part2.to_string()), "{backend:?}");
+        }
+    }
+}
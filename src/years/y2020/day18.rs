@@ -0,0 +1,269 @@
+use std::io::BufRead;
+
+use crate::{
+    day_solver::{DaySolver, StreamingDaySolver},
+    error::{Error, Result},
+    util::for_each_line,
+};
+
+pub struct Day18;
+
+type N = u64;
+
+/// The expressions, or when parsed from a reader, what they add up to under each part's rules so
+/// the lines don't have to be kept.
+#[derive(Clone)]
+pub enum Homework<'a> {
+    Lines(Vec<&'a [u8]>),
+    Sums([N; 2]),
+}
+
+#[derive(Clone, Copy)]
+enum Op {
+    Add,
+    Mult,
+}
+
+use Op::*;
+
+impl<'a> DaySolver<'a> for Day18 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 18;
+
+    type Parsed = Homework<'a>;
+    type Output = N;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        input
+            .as_bytes()
+            .split(|&x| x == b'\n')
+            .map(|l| validate(input.as_bytes(), l).map(|()| l))
+            .collect::<Result<_>>()
+            .map(Homework::Lines)
+    }
+
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        match data {
+            Homework::Lines(lines) => sum(lines.iter().map(|e| eval(&mut e.iter().copied()))),
+            Homework::Sums(sums) => Ok(sums[0]),
+        }
+    }
+
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        match data {
+            Homework::Lines(lines) => sum(lines
+                .iter()
+                .map(|e| eval(&mut transform(e.iter().copied())))),
+            Homework::Sums(sums) => Ok(sums[1]),
+        }
+    }
+}
+
+impl StreamingDaySolver<'_> for Day18 {
+    fn parse_reader(reader: impl BufRead) -> Result<Self::Parsed> {
+        let mut sums = [0; 2];
+        for_each_line(reader, |_, line| {
+            validate(line, line)?;
+            sums = [
+                sum([Some(sums[0]), eval(&mut line.iter().copied())])?,
+                sum([Some(sums[1]), eval(&mut transform(line.iter().copied()))])?,
+            ];
+            Ok(())
+        })?;
+        Ok(Homework::Sums(sums))
+    }
+}
+
+fn sum(values: impl IntoIterator<Item = Option<N>>) -> Result<N> {
+    values
+        .into_iter()
+        .try_fold(0, |total: N, x| total.checked_add(x?))
+        .ok_or_else(|| Error::no_solution("the homework adds up past u64"))
+}
+
+/// Checks the expression is well formed, so `eval` never has to.
+fn validate(input: &[u8], line: &[u8]) -> Result<()> {
+    let mut depth = 0_usize;
+    let mut want_operand = true;
+
+    for (i, &c) in line.iter().enumerate() {
+        match (want_operand, c) {
+            (_, b' ') => {}
+            (true, b'0'..=b'9') => want_operand = false,
+            (true, b'(') => depth += 1,
+            (true, _) => return Err(Error::parse(input, &line[i..], "a digit or `(`")),
+            (false, b'+' | b'*') => want_operand = true,
+            (false, b')') if depth > 0 => depth -= 1,
+            (false, _) => return Err(Error::parse(input, &line[i..], "`+`, `*` or `)`")),
+        }
+    }
+
+    if want_operand || depth > 0 {
+        Err(Error::parse(
+            input,
+            &line[line.len()..],
+            "a complete expression",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn transform(source: impl Iterator<Item = u8>) -> impl Iterator<Item = u8> {
+    Part2Wrapper {
+        source,
+        previous: Some(b'('),
+        previous2: None,
+    }
+}
+
+struct Part2Wrapper<T: Iterator<Item = u8>> {
+    source: T,
+    previous: Option<u8>,
+    previous2: Option<u8>,
+}
+
+impl<T: Iterator<Item = u8>> Iterator for Part2Wrapper<T> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(x) = self.previous {
+            self.previous = self.previous2.take();
+            Some(x)
+        } else if let Some(x) = self.source.next() {
+            match x {
+                b'*' => {
+                    self.previous = Some(x);
+                    self.previous2 = Some(b'(');
+                    Some(b')')
+                }
+                b'(' => {
+                    self.previous = Some(b'(');
+                    Some(b'(')
+                }
+                b')' => {
+                    self.previous = Some(b')');
+                    Some(b')')
+                }
+                x => Some(x),
+            }
+        } else {
+            Some(b')')
+        }
+    }
+}
+
+/// `None` if it overflows, or if the expression is malformed, which `parse` already rules out.
+fn eval(tokens: &mut impl Iterator<Item = u8>) -> Option<N> {
+    let mut prev_op = Some(Add);
+    let mut result = 0;
+
+    while let Some(i) = tokens.find(|&x| x != b' ') {
+        match i {
+            b'0'..=b'9' => {
+                let x = (i - b'0') as N;
+                result = apply_op(prev_op.take()?, result, x)?;
+            }
+            b'+' => {
+                debug_assert!(prev_op.is_none());
+                prev_op = Some(Add);
+            }
+            b'*' => {
+                debug_assert!(prev_op.is_none());
+                prev_op = Some(Mult);
+            }
+            b'(' => {
+                let res = eval(tokens)?;
+                result = apply_op(prev_op.take()?, result, res)?;
+            }
+            b')' => break,
+            _ => return None,
+        }
+    }
+
+    Some(result)
+}
+
+fn apply_op(prev_op: Op, result: N, x: N) -> Option<N> {
+    match prev_op {
+        Add => result.checked_add(x),
+        Mult => result.checked_mul(x),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn d18p1() {
+        assert_eq!(
+            Day18::part1(&Day18::parse("1 + 2 * 3 + 4 * 5 + 6").unwrap()).unwrap(),
+            71
+        );
+        assert_eq!(
+            Day18::part1(&Day18::parse("1 + (2 * 3) + (4 * (5 + 6))").unwrap()).unwrap(),
+            51
+        );
+        assert_eq!(
+            Day18::part1(&Day18::parse("2 * 3 + (4 * 5)").unwrap()).unwrap(),
+            26
+        );
+        assert_eq!(
+            Day18::part1(&Day18::parse("5 + (8 * 3 + 9 + 3 * 4 * 3)").unwrap()).unwrap(),
+            437
+        );
+        assert_eq!(
+            Day18::part1(&Day18::parse("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))").unwrap())
+                .unwrap(),
+            12240
+        );
+        assert_eq!(
+            Day18::part1(&Day18::parse("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2").unwrap())
+                .unwrap(),
+            13632
+        );
+    }
+
+    #[test]
+    fn d18p2() {
+        assert_eq!(
+            Day18::part2(&Day18::parse("1 + 2 * 3 + 4 * 5 + 6").unwrap()).unwrap(),
+            231
+        );
+        assert_eq!(
+            Day18::part1(&Day18::parse("1 + (2 * 3) + (4 * (5 + 6))").unwrap()).unwrap(),
+            51
+        );
+        assert_eq!(
+            Day18::part2(&Day18::parse("2 * 3 + (4 * 5)").unwrap()).unwrap(),
+            46
+        );
+        assert_eq!(
+            Day18::part2(&Day18::parse("5 + (8 * 3 + 9 + 3 * 4 * 3)").unwrap()).unwrap(),
+            1445
+        );
+        assert_eq!(
+            Day18::part2(&Day18::parse("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))").unwrap())
+                .unwrap(),
+            669_060
+        );
+        assert_eq!(
+            Day18::part2(&Day18::parse("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2").unwrap())
+                .unwrap(),
+            23340
+        );
+    }
+
+    #[test]
+    fn overflow() {
+        let input = ["9"; 21].join(" * ");
+        for part in [Day18::part1, Day18::part2] {
+            assert_eq!(
+                part(&Day18::parse(&input).unwrap()).unwrap_err().kind(),
+                &crate::error::ErrorKind::NoSolution("the homework adds up past u64")
+            );
+        }
+        assert!(Day18::parse_reader(input.as_bytes()).is_err());
+    }
+}
@@ -0,0 +1,380 @@
+use std::num::NonZeroUsize;
+
+use arrayvec::ArrayVec;
+use rustc_hash::FxHashMap;
+
+use crate::{
+    day_solver::DaySolver,
+    error::{Error, Result},
+    util::*,
+};
+
+pub struct Day19;
+
+type N = u8;
+
+#[derive(Clone)]
+pub enum Rule {
+    Character(u8),
+    OneSide(ArrayVec<N, 3>),
+    TwoSide(ArrayVec<N, 3>, ArrayVec<N, 3>),
+}
+
+use Rule::*;
+
+impl<'a> DaySolver<'a> for Day19 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 19;
+
+    type Parsed = (FxHashMap<N, Rule>, Vec<&'a [u8]>);
+    type Output = usize;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        let mut rulemap = FxHashMap::default();
+        let mut references = Vec::new();
+        let mut lines = input.as_bytes().split(|&x| x == b'\n');
+
+        for l in lines.by_ref() {
+            if l.is_empty() {
+                break;
+            }
+
+            let colon = l
+                .iter()
+                .position(|&x| x == b':')
+                .ok_or_else(|| Error::parse(input, l, "a rule id and `:`"))?;
+            let id = l[..colon].parse_in(input)?;
+            let mut pos = colon + 2;
+
+            rulemap.insert(
+                id,
+                match (l.get(pos), l.get(pos + 1)) {
+                    (Some(b'"'), Some(&c)) => Character(c),
+                    (Some(_), _) => {
+                        let mut first = ArrayVec::new();
+                        let mut second = ArrayVec::new();
+
+                        loop {
+                            let i = l[pos..]
+                                .iter()
+                                .position(|&x| x == b' ')
+                                .unwrap_or(l.len() - pos);
+                            let word = &l[pos..pos + i];
+                            if let Ok(num) = word.parse() {
+                                first
+                                    .try_push(num)
+                                    .map_err(|_| Error::parse(input, word, "at most 3 subrules"))?;
+                                references.push((id, num, word));
+                            } else if word == b"|" && second.is_empty() {
+                                std::mem::swap(&mut first, &mut second);
+                            } else {
+                                return Err(Error::parse(input, word, "a rule id or `|`"));
+                            }
+
+                            pos += i + 1;
+                            if pos >= l.len() {
+                                break;
+                            }
+                        }
+
+                        if second.is_empty() {
+                            OneSide(first)
+                        } else {
+                            TwoSide(second, first)
+                        }
+                    }
+                    (None, _) => return Err(Error::parse(input, &l[l.len()..], "a rule")),
+                },
+            );
+        }
+
+        if let Some(&(_, _, word)) = references.iter().find(|(_, r, _)| !rulemap.contains_key(r)) {
+            return Err(Error::parse(input, word, "a defined rule id"));
+        }
+
+        if let Some(word) = find_cycle(&references) {
+            return Err(Error::parse(
+                input,
+                word,
+                "a rule that doesn't lead back to itself",
+            ));
+        }
+
+        Ok((rulemap, lines.collect()))
+    }
+
+    fn part1((rules, lines): &Self::Parsed) -> Result<Self::Output> {
+        check_rules(rules, &[0])?;
+
+        Ok(lines
+            .iter()
+            .filter(|l| recursive_match(l, rules, 0).is_some_and(|x| x.get() == l.len()))
+            .count())
+    }
+
+    fn part2((rules, lines): &Self::Parsed) -> Result<Self::Output> {
+        check_rules(rules, &[42, 31])?;
+        let mut count = 0;
+
+        for l in lines {
+            let mut ftcount = 0;
+            let mut ftpos = 0;
+            while let Some(x) = recursive_match(&l[ftpos..], rules, 42) {
+                ftcount += 1;
+                ftpos += x.get();
+
+                let mut topos = ftpos;
+                for _ in 1..ftcount {
+                    if let Some(x) = recursive_match(&l[topos..], rules, 31) {
+                        topos += x.get();
+                        if topos == l.len() {
+                            count += 1;
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(count)
+    }
+}
+
+fn check_rules(rules: &FxHashMap<N, Rule>, needed: &[N]) -> Result<()> {
+    if needed.iter().all(|r| rules.contains_key(r)) {
+        Ok(())
+    } else {
+        Err(Error::no_solution("a required rule is missing"))
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum Visit {
+    Unseen,
+    Active,
+    Done,
+}
+
+/// Matching a rule that can reach itself again would recurse forever, so finds the reference
+/// that closes a loop, if there is one.
+fn find_cycle<'a>(references: &[(N, N, &'a [u8])]) -> Option<&'a [u8]> {
+    fn visit<'a>(id: N, subrules: &[Vec<(N, &'a [u8])>], state: &mut [Visit]) -> Option<&'a [u8]> {
+        state[id as usize] = Visit::Active;
+        for &(sub, word) in &subrules[id as usize] {
+            let cycle = match state[sub as usize] {
+                Visit::Active => Some(word),
+                Visit::Unseen => visit(sub, subrules, state),
+                Visit::Done => None,
+            };
+            if cycle.is_some() {
+                return cycle;
+            }
+        }
+        state[id as usize] = Visit::Done;
+        None
+    }
+
+    let mut subrules = vec![Vec::new(); N::MAX as usize + 1];
+    for &(id, sub, word) in references {
+        subrules[id as usize].push((sub, word));
+    }
+
+    let mut state = vec![Visit::Unseen; subrules.len()];
+    (0..=N::MAX).find_map(|id| {
+        if state[id as usize] == Visit::Unseen {
+            visit(id, &subrules, &mut state)
+        } else {
+            None
+        }
+    })
+}
+
+fn recursive_match(l: &[u8], rules: &FxHashMap<N, Rule>, me: N) -> Option<NonZeroUsize> {
+    match rules.get(&me)? {
+        Character(a) => {
+            if l.first() == Some(a) {
+                NonZeroUsize::new(1)
+            } else {
+                None
+            }
+        }
+        OneSide(subrules) => handle_subrules(l, rules, subrules),
+        TwoSide(left, right) => {
+            if let Some(x) = handle_subrules(l, rules, left) {
+                Some(x)
+            } else {
+                handle_subrules(l, rules, right)
+            }
+        }
+    }
+}
+
+fn handle_subrules(l: &[u8], rules: &FxHashMap<N, Rule>, subrules: &[N]) -> Option<NonZeroUsize> {
+    let mut pos = 0;
+
+    for &rule in subrules {
+        if let Some(x) = recursive_match(&l[pos..], rules, rule) {
+            pos += x.get();
+        } else {
+            return None;
+        }
+    }
+
+    NonZeroUsize::new(pos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn d19p1() {
+        assert_eq!(
+            Day19::part1(
+                &Day19::parse(
+                    "0: 4 1 5
+1: 2 3 | 3 2
+2: 4 4 | 5 5
+3: 4 5 | 5 4
+4: \"a\"
+5: \"b\"
+
+ababbb
+bababa
+abbbab
+aaabbb
+aaaabbb"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            2
+        );
+
+        assert_eq!(
+            Day19::part1(
+                &Day19::parse(
+                    "42: 9 14 | 10 1
+9: 14 27 | 1 26
+10: 23 14 | 28 1
+1: \"a\"
+11: 42 31
+5: 1 14 | 15 1
+19: 14 1 | 14 14
+12: 24 14 | 19 1
+16: 15 1 | 14 14
+31: 14 17 | 1 13
+6: 14 14 | 1 14
+2: 1 24 | 14 4
+0: 8 11
+13: 14 3 | 1 12
+15: 1 | 14
+17: 14 2 | 1 7
+23: 25 1 | 22 14
+28: 16 1
+4: 1 1
+20: 14 14 | 1 15
+3: 5 14 | 16 1
+27: 1 6 | 14 18
+14: \"b\"
+21: 14 1 | 1 14
+25: 1 1 | 1 14
+22: 14 14
+8: 42
+26: 14 22 | 1 20
+18: 15 15
+7: 14 5 | 1 21
+24: 14 1
+
+abbbbbabbbaaaababbaabbbbabababbbabbbbbbabaaaa
+bbabbbbaabaabba
+babbbbaabbbbbabbbbbbaabaaabaaa
+aaabbbbbbaaaabaababaabababbabaaabbababababaaa
+bbbbbbbaaaabbbbaaabbabaaa
+bbbababbbbaaaaaaaabbababaaababaabab
+ababaaaaaabaaab
+ababaaaaabbbaba
+baabbaaaabbaaaababbaababb
+abbbbabbbbaaaababbbbbbaaaababb
+aaaaabbaabaaaaababaa
+aaaabbaaaabbaaa
+aaaabbaabbaaaaaaabbbabbbaaabbaabaaa
+babaaabbbaaabaababbaabababaaab
+aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            3
+        );
+    }
+
+    #[test]
+    fn d19p2() {
+        assert_eq!(
+            Day19::part2(
+                &Day19::parse(
+                    "42: 9 14 | 10 1
+9: 14 27 | 1 26
+10: 23 14 | 28 1
+1: \"a\"
+11: 42 31
+5: 1 14 | 15 1
+19: 14 1 | 14 14
+12: 24 14 | 19 1
+16: 15 1 | 14 14
+31: 14 17 | 1 13
+6: 14 14 | 1 14
+2: 1 24 | 14 4
+0: 8 11
+13: 14 3 | 1 12
+15: 1 | 14
+17: 14 2 | 1 7
+23: 25 1 | 22 14
+28: 16 1
+4: 1 1
+20: 14 14 | 1 15
+3: 5 14 | 16 1
+27: 1 6 | 14 18
+14: \"b\"
+21: 14 1 | 1 14
+25: 1 1 | 1 14
+22: 14 14
+8: 42
+26: 14 22 | 1 20
+18: 15 15
+7: 14 5 | 1 21
+24: 14 1
+
+abbbbbabbbaaaababbaabbbbabababbbabbbbbbabaaaa
+bbabbbbaabaabba
+babbbbaabbbbbabbbbbbaabaaabaaa
+aaabbbbbbaaaabaababaabababbabaaabbababababaaa
+bbbbbbbaaaabbbbaaabbabaaa
+bbbababbbbaaaaaaaabbababaaababaabab
+ababaaaaaabaaab
+ababaaaaabbbaba
+baabbaaaabbaaaababbaababb
+abbbbabbbbaaaababbbbbbaaaababb
+aaaaabbaabaaaaababaa
+aaaabbaaaabbaaa
+aaaabbaabbaaaaaaabbbabbbaaabbaabaaa
+babaaabbbaaabaababbaabababaaab
+aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            12
+        );
+    }
+
+    #[test]
+    fn cyclic_rules() {
+        let err = Day19::parse("0: 1\n1: 0\n\na").err().unwrap();
+        assert!(matches!(
+            err.kind(),
+            crate::error::ErrorKind::Parse { line: 2, .. }
+        ));
+    }
+}
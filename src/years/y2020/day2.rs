@@ -0,0 +1,123 @@
+use std::{borrow::Cow, io::BufRead};
+
+use serde_scan::scan;
+
+use crate::{
+    day_solver::{DaySolver, StreamingDaySolver},
+    error::{Error, Result},
+    util::for_each_line,
+};
+
+pub struct Day2;
+
+#[derive(Clone)]
+#[allow(clippy::struct_field_names)]
+pub struct Password<'a> {
+    num1: usize,
+    num2: usize,
+    character: u8,
+    /// Only owned when parsed from a reader.
+    password: Cow<'a, [u8]>,
+}
+
+impl<'a> DaySolver<'a> for Day2 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 2;
+
+    type Parsed = Vec<Password<'a>>;
+    type Output = usize;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        input.lines().map(|x| parse_password(input, x)).collect()
+    }
+
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        Ok(data
+            .iter()
+            .filter(|p| (p.num1..=p.num2).contains(&bytecount::count(&p.password, p.character)))
+            .count())
+    }
+
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        Ok(data
+            .iter()
+            .filter(|p| {
+                (p.password[p.num1 - 1] == p.character) != (p.password[p.num2 - 1] == p.character)
+            })
+            .count())
+    }
+}
+
+impl StreamingDaySolver<'_> for Day2 {
+    fn parse_reader(reader: impl BufRead) -> Result<Self::Parsed> {
+        let mut res = Vec::new();
+        for_each_line(reader, |_, line| {
+            let line = std::str::from_utf8(line)
+                .map_err(|_| Error::parse(line, line, "a password policy"))?;
+            let p = parse_password(line, line)?;
+            res.push(Password {
+                password: Cow::Owned(p.password.into_owned()),
+                ..p
+            });
+            Ok(())
+        })?;
+        Ok(res)
+    }
+}
+
+fn parse_password<'a>(input: &str, x: &'a str) -> Result<Password<'a>> {
+    let (num1, num2, character, password): (_, _, char, &[u8]) = scan!("{}-{} {}: {}" <- x)
+        .map_err(|_| Error::parse(input, x.as_bytes(), "a password policy"))?;
+
+    if num1 == 0 || num1 > num2 || num2 > password.len() {
+        return Err(Error::parse(
+            input,
+            x.as_bytes(),
+            "policy positions within the password",
+        ));
+    }
+
+    Ok(Password {
+        num1,
+        num2,
+        character: character as u8,
+        password: Cow::Borrowed(password),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn d2p1() {
+        assert_eq!(
+            Day2::part1(
+                &Day2::parse(
+                    "1-3 a: abcde
+1-3 b: cdefg
+2-9 c: ccccccccc"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            2
+        );
+    }
+
+    #[test]
+    fn d2p2() {
+        assert_eq!(
+            Day2::part2(
+                &Day2::parse(
+                    "1-3 a: abcde
+1-3 b: cdefg
+2-9 c: ccccccccc"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            1
+        );
+    }
+}
@@ -0,0 +1,503 @@
+use petgraph::{graph::NodeIndex, Graph, Undirected};
+use rustc_hash::FxHashMap;
+use serde_scan::scan;
+
+use crate::{
+    day_solver::DaySolver,
+    error::{Error, Result},
+    util::*,
+};
+
+pub struct Day20;
+
+#[derive(Clone)]
+pub struct Tile {
+    id: u16,
+    /// Borders included, `true` for `#`.
+    tile: Grid<bool>,
+}
+
+impl DaySolver<'_> for Day20 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 20;
+
+    type Parsed = Graph<Tile, (), Undirected>;
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        let mut map = Graph::new_undirected();
+        let mut sides = FxHashMap::default();
+        let mut size = None;
+
+        let mut lines = input.lines();
+
+        while let Some(l) = lines.next() {
+            let id = scan!("Tile {}:" <- l)
+                .map_err(|_| Error::parse(input, l.as_bytes(), "a tile header"))?;
+
+            let first = lines
+                .next()
+                .ok_or_else(|| Error::eof(input, "a row of the tile"))?;
+            if first.len() < 3 {
+                return Err(Error::parse(input, first.as_bytes(), "a wider tile"));
+            }
+            if *size.get_or_insert(first.len()) != first.len() {
+                return Err(Error::parse(
+                    input,
+                    first.as_bytes(),
+                    "a tile as wide as the first",
+                ));
+            }
+
+            let mut rows = vec![first];
+            while rows.len() < first.len() {
+                let l = lines
+                    .next()
+                    .ok_or_else(|| Error::eof(input, "a row of the tile"))?;
+                if l.len() != first.len() {
+                    return Err(Error::parse(
+                        input,
+                        l.as_bytes(),
+                        "a row as wide as the tile",
+                    ));
+                }
+                rows.push(l);
+            }
+
+            if let Some(l) = lines.next().filter(|l| !l.is_empty()) {
+                return Err(Error::parse(input, l.as_bytes(), "a square tile"));
+            }
+
+            let tile: Grid<_> = rows.iter().map(|r| r.bytes().map(|c| c == b'#')).collect();
+            let nid = map.add_node(Tile { id, tile });
+
+            // Every border, both ways round, so flipped tiles still match.
+            for o in Orientation::all() {
+                let top = border(&map[nid].tile, o, Side::Top).collect();
+                handle_side(&mut map, &mut sides, top, nid);
+            }
+        }
+
+        Ok(map)
+    }
+
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        data.node_indices()
+            .filter(|&x| data.edges(x).count() == 2)
+            .try_fold(1_u64, |product, x| product.checked_mul(data[x].id as u64))
+            .ok_or_else(|| Error::no_solution("the corner ids multiply past u64"))
+    }
+
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        rough_water(&assemble(data)?)
+    }
+}
+
+fn handle_side(
+    map: &mut <Day20 as DaySolver>::Parsed,
+    sides: &mut FxHashMap<Vec<bool>, NodeIndex>,
+    new: Vec<bool>,
+    id: NodeIndex,
+) {
+    if let Some(other) = sides.remove(&new) {
+        map.update_edge(id, other, ());
+    } else {
+        sides.insert(new, id);
+    }
+}
+
+/// One of the 8 ways to turn or flip a square. By its bits from the top: swap rows with
+/// columns, then reverse the rows, then reverse the columns.
+#[derive(Copy, Clone)]
+struct Orientation(u8);
+
+impl Orientation {
+    fn all() -> impl Iterator<Item = Self> {
+        (0..8).map(Self)
+    }
+
+    /// Where `(row, col)` of the turned square comes from in the original, which is `n` wide.
+    fn source(self, (row, col): Point, n: usize) -> Point {
+        let (row, col) = if self.0 & 4 == 0 {
+            (row, col)
+        } else {
+            (col, row)
+        };
+        (
+            if self.0 & 2 == 0 { row } else { n - 1 - row },
+            if self.0 & 1 == 0 { col } else { n - 1 - col },
+        )
+    }
+}
+
+#[derive(Copy, Clone)]
+enum Side {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+/// The border on `side` of the tile turned by `o`, left to right or top to bottom.
+fn border(tile: &Grid<bool>, o: Orientation, side: Side) -> impl Iterator<Item = bool> + '_ {
+    let n = tile.len();
+    (0..n).map(move |i| {
+        let p = match side {
+            Side::Top => (0, i),
+            Side::Bottom => (n - 1, i),
+            Side::Left => (i, 0),
+            Side::Right => (i, n - 1),
+        };
+        tile[o.source(p, n)]
+    })
+}
+
+/// Lays the tiles out in a square, turned so their borders line up, and stitches them together
+/// without the borders.
+fn assemble(data: &<Day20 as DaySolver>::Parsed) -> Result<Grid<bool>> {
+    let count = data.node_count();
+    let side = count.isqrt();
+    if count == 0 || side * side != count {
+        return Err(Error::no_solution("the tiles don't make a square"));
+    }
+
+    // A corner turned so the tiles next to it are to its right and below it.
+    let corner = data
+        .node_indices()
+        .find(|&n| data.edges(n).count() == 2.min(count - 1))
+        .ok_or_else(|| Error::no_solution("no corner tiles"))?;
+    let fits = |n: NodeIndex, o, side, other| {
+        data.neighbors(n).any(|m| {
+            Orientation::all()
+                .any(|mo| border(&data[n].tile, o, side).eq(border(&data[m].tile, mo, other)))
+        })
+    };
+    let corner_orientation = Orientation::all()
+        .find(|&o| {
+            count == 1
+                || (fits(corner, o, Side::Right, Side::Left)
+                    && fits(corner, o, Side::Bottom, Side::Top))
+        })
+        .ok_or_else(|| Error::no_solution("the corner tile doesn't fit"))?;
+
+    let mut placed = vec![(corner, corner_orientation)];
+    while placed.len() < count {
+        let ((anchor, anchor_o), edge, to) = if placed.len().is_multiple_of(side) {
+            (placed[placed.len() - side], Side::Bottom, Side::Top)
+        } else {
+            (placed[placed.len() - 1], Side::Right, Side::Left)
+        };
+
+        let next = data
+            .neighbors(anchor)
+            .filter(|n| placed.iter().all(|(p, _)| p != n))
+            .find_map(|n| {
+                Orientation::all()
+                    .find(|&o| {
+                        border(&data[anchor].tile, anchor_o, edge).eq(border(&data[n].tile, o, to))
+                    })
+                    .map(|o| (n, o))
+            })
+            .ok_or_else(|| Error::no_solution("the tiles don't fit together"))?;
+        placed.push(next);
+    }
+
+    let n = data[corner].tile.len();
+    let inner = n - 2;
+    Ok((0..side * inner)
+        .map(|row| {
+            let placed = &placed;
+            (0..side * inner).map(move |col| {
+                let (tile, o) = placed[row / inner * side + col / inner];
+                data[tile].tile[o.source((row % inner + 1, col % inner + 1), n)]
+            })
+        })
+        .collect())
+}
+
+const MONSTER: [&[u8]; 3] = [
+    b"                  # ",
+    b"#    ##    ##    ###",
+    b" #  #  #  #  #  #   ",
+];
+
+/// How many `#` in the image aren't part of a sea monster, in whichever orientation has some.
+fn rough_water(image: &Grid<bool>) -> Result<u64> {
+    let monster: Vec<Point> = MONSTER
+        .iter()
+        .enumerate()
+        .flat_map(|(r, row)| {
+            row.iter()
+                .enumerate()
+                .filter(|(_, &c)| c == b'#')
+                .map(move |(c, _)| (r, c))
+        })
+        .collect();
+    let n = image.len();
+    let rough = image.flat_iter().filter(|&&x| x).count();
+
+    for o in Orientation::all() {
+        let mut seen = Grid::from_value(false, n, n);
+        for row in 0..(n + 1).saturating_sub(MONSTER.len()) {
+            for col in 0..(n + 1).saturating_sub(MONSTER[0].len()) {
+                let at = monster.iter().map(|&(r, c)| (row + r, col + c));
+                if at.clone().all(|p| image[o.source(p, n)]) {
+                    at.for_each(|p| seen[p] = true);
+                }
+            }
+        }
+
+        let monsters = seen.flat_iter().filter(|&&x| x).count();
+        if monsters > 0 {
+            return Ok((rough - monsters) as u64);
+        }
+    }
+
+    Err(Error::no_solution("there are no sea monsters"))
+}
+
+#[cfg(test)]
+#[allow(clippy::too_many_lines)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn d20p1() {
+        assert_eq!(
+            Day20::part1(
+                &Day20::parse(
+                    "Tile 2311:
+..##.#..#.
+##..#.....
+#...##..#.
+####.#...#
+##.##.###.
+##...#.###
+.#.#.#..##
+..#....#..
+###...#.#.
+..###..###
+
+Tile 1951:
+#.##...##.
+#.####...#
+.....#..##
+#...######
+.##.#....#
+.###.#####
+###.##.##.
+.###....#.
+..#.#..#.#
+#...##.#..
+
+Tile 1171:
+####...##.
+#..##.#..#
+##.#..#.#.
+.###.####.
+..###.####
+.##....##.
+.#...####.
+#.##.####.
+####..#...
+.....##...
+
+Tile 1427:
+###.##.#..
+.#..#.##..
+.#.##.#..#
+#.#.#.##.#
+....#...##
+...##..##.
+...#.#####
+.#.####.#.
+..#..###.#
+..##.#..#.
+
+Tile 1489:
+##.#.#....
+..##...#..
+.##..##...
+..#...#...
+#####...#.
+#..#.#.#.#
+...#.#.#..
+##.#...##.
+..##.##.##
+###.##.#..
+
+Tile 2473:
+#....####.
+#..#.##...
+#.##..#...
+######.#.#
+.#...#.#.#
+.#########
+.###.#..#.
+########.#
+##...##.#.
+..###.#.#.
+
+Tile 2971:
+..#.#....#
+#...###...
+#.#.###...
+##.##..#..
+.#####..##
+.#..####.#
+#..#.#..#.
+..####.###
+..#.#.###.
+...#.#.#.#
+
+Tile 2729:
+...#.#.#.#
+####.#....
+..#.#.....
+....#..#.#
+.##..##.#.
+.#.####...
+####.#.#..
+##.####...
+##..#.##..
+#.##...##.
+
+Tile 3079:
+#.#.#####.
+.#..######
+..#.......
+######....
+####.#..#.
+.#...#.##.
+#.#####.##
+..#.###...
+..#.......
+..#.###..."
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            20_899_048_083_289
+        );
+    }
+
+    #[test]
+    fn d20p2() {
+        assert_eq!(
+            Day20::part2(
+                &Day20::parse(
+                    "Tile 2311:
+..##.#..#.
+##..#.....
+#...##..#.
+####.#...#
+##.##.###.
+##...#.###
+.#.#.#..##
+..#....#..
+###...#.#.
+..###..###
+
+Tile 1951:
+#.##...##.
+#.####...#
+.....#..##
+#...######
+.##.#....#
+.###.#####
+###.##.##.
+.###....#.
+..#.#..#.#
+#...##.#..
+
+Tile 1171:
+####...##.
+#..##.#..#
+##.#..#.#.
+.###.####.
+..###.####
+.##....##.
+.#...####.
+#.##.####.
+####..#...
+.....##...
+
+Tile 1427:
+###.##.#..
+.#..#.##..
+.#.##.#..#
+#.#.#.##.#
+....#...##
+...##..##.
+...#.#####
+.#.####.#.
+..#..###.#
+..##.#..#.
+
+Tile 1489:
+##.#.#....
+..##...#..
+.##..##...
+..#...#...
+#####...#.
+#..#.#.#.#
+...#.#.#..
+##.#...##.
+..##.##.##
+###.##.#..
+
+Tile 2473:
+#....####.
+#..#.##...
+#.##..#...
+######.#.#
+.#...#.#.#
+.#########
+.###.#..#.
+########.#
+##...##.#.
+..###.#.#.
+
+Tile 2971:
+..#.#....#
+#...###...
+#.#.###...
+##.##..#..
+.#####..##
+.#..####.#
+#..#.#..#.
+..####.###
+..#.#.###.
+...#.#.#.#
+
+Tile 2729:
+...#.#.#.#
+####.#....
+..#.#.....
+....#..#.#
+.##..##.#.
+.#.####...
+####.#.#..
+##.####...
+##..#.##..
+#.##...##.
+
+Tile 3079:
+#.#.#####.
+.#..######
+..#.......
+######....
+####.#..#.
+.#...#.##.
+#.#####.##
+..#.###...
+..#.......
+..#.###..."
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            273
+        );
+    }
+}
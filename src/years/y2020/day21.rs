@@ -0,0 +1,151 @@
+use rustc_hash::FxHashMap;
+
+use crate::{
+    day_solver::DaySolver,
+    error::{Error, Result},
+};
+
+pub struct Day21;
+
+#[derive(Clone)]
+pub struct Food<'a> {
+    ingredients: Vec<&'a [u8]>,
+    allergens: Vec<&'a [u8]>,
+}
+
+impl<'a> DaySolver<'a> for Day21 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 21;
+
+    type Parsed = Vec<Food<'a>>;
+    // Part 2 is a list of ingredients rather than a number.
+    type Output = String;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        input
+            .as_bytes()
+            .split(|&x| x == b'\n')
+            .map(|line| {
+                let (ingredients, allergens) = match line.iter().position(|&c| c == b'(') {
+                    Some(i) => {
+                        let allergens = line[i..]
+                            .strip_prefix(b"(contains ")
+                            .and_then(|a| a.strip_suffix(b")"))
+                            .ok_or_else(|| Error::parse(input, &line[i..], "`(contains ...)`"))?;
+                        (&line[..i], allergens)
+                    }
+                    None => (line, &line[line.len()..]),
+                };
+
+                let ingredients: Vec<_> = ingredients
+                    .split(|&c| c == b' ')
+                    .filter(|i| !i.is_empty())
+                    .collect();
+                if ingredients.is_empty() {
+                    return Err(Error::parse(input, line, "a list of ingredients"));
+                }
+
+                Ok(Food {
+                    ingredients,
+                    allergens: allergens
+                        .split(|&c| c == b',')
+                        .map(|a| a.strip_prefix(b" ").unwrap_or(a))
+                        .filter(|a| !a.is_empty())
+                        .collect(),
+                })
+            })
+            .collect()
+    }
+
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        let candidates = candidates(data);
+
+        Ok(data
+            .iter()
+            .flat_map(|f| &f.ingredients)
+            .filter(|i| !candidates.values().any(|c| c.contains(i)))
+            .count()
+            .to_string())
+    }
+
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        let mut candidates: Vec<_> = candidates(data).into_iter().collect();
+        let mut dangerous = Vec::with_capacity(candidates.len());
+
+        while !candidates.is_empty() {
+            let i = candidates
+                .iter()
+                .position(|(_, c)| c.len() == 1)
+                .ok_or_else(|| Error::no_solution("can't tell which ingredient has an allergen"))?;
+            let (allergen, ingredient) = candidates.swap_remove(i);
+            let ingredient = ingredient[0];
+
+            for (_, c) in &mut candidates {
+                c.retain(|&i| i != ingredient);
+            }
+            dangerous.push((allergen, ingredient));
+        }
+
+        dangerous.sort_unstable();
+        let names: Vec<_> = dangerous
+            .iter()
+            .map(|(_, i)| String::from_utf8_lossy(i))
+            .collect();
+        Ok(names.join(","))
+    }
+}
+
+/// For each allergen, the ingredients in every food that lists it.
+fn candidates<'a>(data: &<Day21 as DaySolver<'a>>::Parsed) -> FxHashMap<&'a [u8], Vec<&'a [u8]>> {
+    let mut candidates: FxHashMap<_, Vec<_>> = FxHashMap::default();
+
+    for food in data {
+        for &allergen in &food.allergens {
+            candidates
+                .entry(allergen)
+                .and_modify(|c| c.retain(|i| food.ingredients.contains(i)))
+                .or_insert_with(|| food.ingredients.clone());
+        }
+    }
+
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn d21p1() {
+        assert_eq!(
+            Day21::part1(
+                &Day21::parse(
+                    "mxmxvkd kfcds sqjhc nhms (contains dairy, fish)
+trh fvjkl sbzzf mxmxvkd (contains dairy)
+sqjhc fvjkl (contains soy)
+sqjhc mxmxvkd sbzzf (contains fish)"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            "5"
+        );
+    }
+
+    #[test]
+    fn d21p2() {
+        assert_eq!(
+            Day21::part2(
+                &Day21::parse(
+                    "mxmxvkd kfcds sqjhc nhms (contains dairy, fish)
+trh fvjkl sbzzf mxmxvkd (contains dairy)
+sqjhc fvjkl (contains soy)
+sqjhc mxmxvkd sbzzf (contains fish)"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            "mxmxvkd,sqjhc,fvjkl"
+        );
+    }
+}
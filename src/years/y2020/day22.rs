@@ -0,0 +1,239 @@
+use std::collections::VecDeque;
+
+use rustc_hash::FxHashSet;
+
+use crate::{
+    day_solver::DaySolver,
+    error::{Error, Result},
+    util::*,
+};
+
+pub struct Day22;
+
+type Card = u8;
+type Deck = VecDeque<Card>;
+
+impl DaySolver<'_> for Day22 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 22;
+
+    type Parsed = [Deck; 2];
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        let mut decks = [Deck::new(), Deck::new()];
+        let mut dealt = [false; Card::MAX as usize + 1];
+        let mut lines = input.as_bytes().split(|&x| x == b'\n');
+
+        for (deck, header) in decks.iter_mut().zip(&[&b"Player 1:"[..], b"Player 2:"]) {
+            match lines.next() {
+                Some(l) if l == *header => {}
+                Some(l) => return Err(Error::parse(input, l, "a player's header")),
+                None => return Err(Error::eof(input, "a player's header")),
+            }
+
+            for l in lines.by_ref().take_while(|l| !l.is_empty()) {
+                let card: Card = l.parse_in(input)?;
+                if std::mem::replace(&mut dealt[card as usize], true) {
+                    return Err(Error::parse(input, l, "a card that hasn't been dealt yet"));
+                }
+                deck.push_back(card);
+            }
+        }
+
+        Ok(decks)
+    }
+
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        let [mut one, mut two] = data.clone();
+        let mut seen = FxHashSet::default();
+
+        while !one.is_empty() && !two.is_empty() {
+            if !seen.insert((one.clone(), two.clone())) {
+                return Err(Error::no_solution("the game never ends"));
+            }
+
+            let (a, b) = (one.pop_front().unwrap(), two.pop_front().unwrap());
+            if a > b {
+                one.extend([a, b]);
+            } else {
+                two.extend([b, a]);
+            }
+        }
+
+        Ok(score(if one.is_empty() { &two } else { &one }))
+    }
+
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        let [one, two] = data.clone();
+        let (one_won, [one, two]) = recursive_combat(one, two);
+        Ok(score(if one_won { &one } else { &two }))
+    }
+}
+
+fn score(deck: &Deck) -> usize {
+    deck.iter()
+        .rev()
+        .zip(1..)
+        .map(|(&c, i)| c as usize * i)
+        .sum()
+}
+
+/// Plays a game to the end, and is true if player 1 won, along with the decks the game ended with.
+/// Subgames are kept on a stack rather than recursed into, since they can nest as deep as there
+/// are cards.
+fn recursive_combat(one: Deck, two: Deck) -> (bool, [Deck; 2]) {
+    let mut game = Game::new(one, two);
+    // The games waiting on a subgame to decide their round, outermost first.
+    let mut outer = Vec::new();
+
+    loop {
+        let one_won = match game.play() {
+            Played::Subgame(subgame) => {
+                outer.push(std::mem::replace(&mut game, subgame));
+                continue;
+            }
+            Played::Over(one_won) => one_won,
+        };
+
+        match outer.pop() {
+            Some(parent) => {
+                game = parent;
+                game.award(one_won);
+            }
+            None => return (one_won, [game.one, game.two]),
+        }
+    }
+}
+
+struct Game {
+    one: Deck,
+    two: Deck,
+    seen: FxHashSet<(Deck, Deck)>,
+    /// The cards played in the current round.
+    drawn: [Card; 2],
+}
+
+enum Played {
+    /// The game is over, and player 1 won if true.
+    Over(bool),
+    /// The current round can't be decided without playing this first.
+    Subgame(Game),
+}
+
+impl Game {
+    fn new(one: Deck, two: Deck) -> Self {
+        Self {
+            one,
+            two,
+            seen: FxHashSet::default(),
+            drawn: [0; 2],
+        }
+    }
+
+    /// Plays rounds until the game ends or a round needs a subgame.
+    fn play(&mut self) -> Played {
+        while let (Some(&a), Some(&b)) = (self.one.front(), self.two.front()) {
+            if !self.seen.insert((self.one.clone(), self.two.clone())) {
+                return Played::Over(true);
+            }
+
+            self.one.pop_front();
+            self.two.pop_front();
+            self.drawn = [a, b];
+
+            let one_wins = if self.one.len() >= a as usize && self.two.len() >= b as usize {
+                let one: Deck = self.one.iter().take(a as usize).copied().collect();
+                let two: Deck = self.two.iter().take(b as usize).copied().collect();
+                // A subgame's cards are all different, so the highest is at least how many there
+                // are, and too high to start another subgame with. Whoever has it can't lose it,
+                // and a repeat is a win for player 1 anyway, so player 1 wins any subgame where
+                // they have it.
+                if one.iter().max() > two.iter().max() {
+                    true
+                } else {
+                    return Played::Subgame(Self::new(one, two));
+                }
+            } else {
+                a > b
+            };
+            self.award(one_wins);
+        }
+
+        Played::Over(self.two.is_empty())
+    }
+
+    /// Gives the cards played in the current round to its winner.
+    fn award(&mut self, one_won: bool) {
+        let [a, b] = self.drawn;
+        if one_won {
+            self.one.extend([a, b]);
+        } else {
+            self.two.extend([b, a]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn d22p1() {
+        assert_eq!(
+            Day22::part1(
+                &Day22::parse(
+                    "Player 1:
+9
+2
+6
+3
+1
+
+Player 2:
+5
+8
+4
+7
+10"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            306
+        );
+    }
+
+    #[test]
+    fn d22p2() {
+        assert_eq!(
+            Day22::part2(
+                &Day22::parse(
+                    "Player 1:
+9
+2
+6
+3
+1
+
+Player 2:
+5
+8
+4
+7
+10"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            291
+        );
+
+        // Would go on forever without the rule about repeats.
+        assert_eq!(
+            Day22::part2(&Day22::parse("Player 1:\n43\n19\n\nPlayer 2:\n2\n29\n14").unwrap())
+                .unwrap(),
+            105
+        );
+    }
+}
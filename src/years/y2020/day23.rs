@@ -0,0 +1,126 @@
+use crate::{
+    day_solver::DaySolver,
+    error::{Error, Result},
+};
+
+pub struct Day23;
+
+type Cup = u32;
+
+impl DaySolver<'_> for Day23 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 23;
+
+    type Parsed = Vec<Cup>;
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        let input = input.as_bytes();
+        if input.len() < 5 {
+            return Err(Error::parse(input, input, "at least 5 cups"));
+        }
+
+        let mut seen = [false; 10];
+        input
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| {
+                let cup = c.wrapping_sub(b'0');
+                if !(1..=9).contains(&cup)
+                    || cup as usize > input.len()
+                    || std::mem::replace(&mut seen[cup as usize], true)
+                {
+                    return Err(Error::parse(
+                        input,
+                        &input[i..=i],
+                        "each cup from 1 to how many there are once",
+                    ));
+                }
+                Ok(cup as Cup)
+            })
+            .collect()
+    }
+
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        let next = play(data, data.len() as Cup, 100);
+
+        let mut labels = 0;
+        let mut cup = next[1];
+        while cup != 1 {
+            labels = labels * 10 + cup as u64;
+            cup = next[cup as usize];
+        }
+        Ok(labels)
+    }
+
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        let next = play(data, 1_000_000, 10_000_000);
+
+        let a = next[1];
+        let b = next[a as usize];
+        Ok(a as u64 * b as u64)
+    }
+}
+
+/// Makes `moves` moves with `cups` followed by the rest of the cups up to `total` in order.
+/// Returns which cup follows each one clockwise.
+fn play(cups: &[Cup], total: Cup, moves: usize) -> Vec<Cup> {
+    let mut next = vec![0; total as usize + 1];
+
+    // There's no cup 0, so it stands in for the one before the first cup while linking them up.
+    let order = cups.iter().copied().chain(cups.len() as Cup + 1..=total);
+    let last = order.fold(0, |prev, cup| {
+        next[prev as usize] = cup;
+        cup
+    });
+    let first = next[0];
+    next[last as usize] = first;
+
+    let mut current = first;
+    for _ in 0..moves {
+        let a = next[current as usize];
+        let b = next[a as usize];
+        let c = next[b as usize];
+
+        let mut destination = current;
+        loop {
+            destination = if destination == 1 {
+                total
+            } else {
+                destination - 1
+            };
+            if destination != a && destination != b && destination != c {
+                break;
+            }
+        }
+
+        next[current as usize] = next[c as usize];
+        next[c as usize] = next[destination as usize];
+        next[destination as usize] = a;
+        current = next[current as usize];
+    }
+
+    next
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn d23p1() {
+        assert_eq!(
+            Day23::part1(&Day23::parse("389125467").unwrap()).unwrap(),
+            67_384_529
+        );
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "slow in debug builds")]
+    fn d23p2() {
+        assert_eq!(
+            Day23::part2(&Day23::parse("389125467").unwrap()).unwrap(),
+            149_245_887_792
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use std::io::BufRead;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    day_solver::{DaySolver, StreamingDaySolver},
+    error::{Error, Result},
+    util::*,
+};
+
+pub struct Day24;
+
+type N = i32;
+
+/// Axial coordinates: east along the first, southeast along the second.
+type Hex = (N, N);
+
+/// East, southeast, southwest, west, northwest and northeast.
+const NEIGHBORS: [Hex; 6] = [(1, 0), (0, 1), (-1, 1), (-1, 0), (0, -1), (1, -1)];
+
+impl DaySolver<'_> for Day24 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 24;
+
+    /// The tile each line leads to.
+    type Parsed = Vec<Hex>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input
+            .as_bytes()
+            .split(|&x| x == b'\n')
+            .map(|l| parse_line(input, l))
+            .collect()
+    }
+
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        Ok(black_tiles(data).len())
+    }
+
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        let mut black = black_tiles(data);
+        let mut counts: FxHashMap<Hex, u8> = FxHashMap::default();
+
+        for _ in 0..100 {
+            counts.clear();
+            for &(q, r) in &black {
+                for &(dq, dr) in &NEIGHBORS {
+                    *counts.entry((q + dq, r + dr)).or_default() += 1;
+                }
+            }
+
+            black = counts
+                .iter()
+                .filter(|&(hex, &count)| count == 2 || (count == 1 && black.contains(hex)))
+                .map(|(&hex, _)| hex)
+                .collect();
+        }
+
+        Ok(black.len())
+    }
+}
+
+impl StreamingDaySolver<'_> for Day24 {
+    fn parse_reader(reader: impl BufRead) -> Result<Self::Parsed> {
+        let mut res = Vec::new();
+        for_each_line(reader, |_, line| {
+            res.push(parse_line(line, line)?);
+            Ok(())
+        })?;
+        Ok(res)
+    }
+}
+
+/// Follows the directions on `line`, which is somewhere in `input`.
+fn parse_line(input: impl AsRef<[u8]>, line: &[u8]) -> Result<Hex> {
+    let (mut q, mut r) = (0, 0);
+    let mut i = 0;
+
+    while i < line.len() {
+        let (step, len) = match &line[i..] {
+            [b'e', ..] => (NEIGHBORS[0], 1),
+            [b's', b'e', ..] => (NEIGHBORS[1], 2),
+            [b's', b'w', ..] => (NEIGHBORS[2], 2),
+            [b'w', ..] => (NEIGHBORS[3], 1),
+            [b'n', b'w', ..] => (NEIGHBORS[4], 2),
+            [b'n', b'e', ..] => (NEIGHBORS[5], 2),
+            rest => {
+                return Err(Error::parse(
+                    input,
+                    &rest[..rest.len().min(2)],
+                    "`e`, `se`, `sw`, `w`, `nw` or `ne`",
+                ))
+            }
+        };
+        q += step.0;
+        r += step.1;
+        i += len;
+    }
+
+    Ok((q, r))
+}
+
+/// The tiles flipped an odd number of times.
+fn black_tiles(data: &<Day24 as DaySolver>::Parsed) -> FxHashSet<Hex> {
+    let mut black = FxHashSet::default();
+    for hex in data {
+        if !black.remove(hex) {
+            black.insert(*hex);
+        }
+    }
+    black
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn d24p1() {
+        assert_eq!(
+            Day24::part1(
+                &Day24::parse(
+                    "sesenwnenenewseeswwswswwnenewsewsw
+neeenesenwnwwswnenewnwwsewnenwseswesw
+seswneswswsenwwnwse
+nwnwneseeswswnenewneswwnewseswneseene
+swweswneswnenwsewnwneneseenw
+eesenwseswswnenwswnwnwsewwnwsene
+sewnenenenesenwsewnenwwwse
+wenwwweseeeweswwwnwwe
+wsweesenenewnwwnwsenewsenwwsesesenwne
+neeswseenwwswnwswswnw
+nenwswwsewswnenenewsenwsenwnesesenew
+enewnwewneswsewnwswenweswnenwsenwsw
+sweneswneswneneenwnewenewwneswswnese
+swwesenesewenwneswnwwneseswwne
+enesenwswwswneneswsenwnewswseenwsese
+wnwnesenesenenwwnenwsewesewsesesew
+nenewswnwewswnenesenwnesewesw
+eneswnwswnwsenenwnwnwwseeswneewsenese
+neswnwewnwnwseenwseesewsenwsweewe
+wseweeenwnesenwwwswnew"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            10
+        );
+    }
+
+    #[test]
+    fn d24p2() {
+        assert_eq!(
+            Day24::part2(
+                &Day24::parse(
+                    "sesenwnenenewseeswwswswwnenewsewsw
+neeenesenwnwwswnenewnwwsewnenwseswesw
+seswneswswsenwwnwse
+nwnwneseeswswnenewneswwnewseswneseene
+swweswneswnenwsewnwneneseenw
+eesenwseswswnenwswnwnwsewwnwsene
+sewnenenenesenwsewnenwwwse
+wenwwweseeeweswwwnwwe
+wsweesenenewnwwnwsenewsenwwsesesenwne
+neeswseenwwswnwswswnw
+nenwswwsewswnenenewsenwsenwnesesenew
+enewnwewneswsewnwswenweswnenwsenwsw
+sweneswneswneneenwnewenewwneswswnese
+swwesenesewenwneswnwwneseswwne
+enesenwswwswneneswsenwnewswseenwsese
+wnwnesenesenenwwnenwsewesewsesesew
+nenewswnwewswnenesenwnesewesw
+eneswnwswnwsenenwnwnwwseeswneewsenese
+neswnwewnwnwseenwseesewsenwsweewe
+wseweeenwnesenwwwswnew"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            2208
+        );
+    }
+}
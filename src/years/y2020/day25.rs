@@ -0,0 +1,99 @@
+use crate::{
+    day_solver::DaySolver,
+    error::{Error, Result},
+    util::*,
+};
+
+pub struct Day25;
+
+const MODULUS: u64 = 20_201_227;
+const SUBJECT: u64 = 7;
+
+impl DaySolver<'_> for Day25 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 25;
+
+    /// The card's and the door's public keys.
+    type Parsed = [u64; 2];
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        let mut lines = input.as_bytes().split(|&x| x == b'\n');
+        let mut keys = [0; 2];
+
+        for key in &mut keys {
+            let l = lines
+                .next()
+                .ok_or_else(|| Error::eof(input, "a public key"))?;
+            *key = l.parse_in(input)?;
+            if *key == 0 || *key >= MODULUS {
+                return Err(Error::parse(input, l, "a public key below 20201227"));
+            }
+        }
+
+        match lines.next() {
+            Some(l) => Err(Error::parse(input, l, "only two public keys")),
+            None => Ok(keys),
+        }
+    }
+
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        let [card, door] = *data;
+        let loop_size = discrete_log(card);
+        Ok(pow_mod(door, loop_size))
+    }
+
+    fn part2(_: &Self::Parsed) -> Result<Self::Output> {
+        Err(Error::no_solution("day 25 only has one part"))
+    }
+}
+
+fn pow_mod(mut base: u64, mut exp: u64) -> u64 {
+    let mut res = 1;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            res = res * base % MODULUS;
+        }
+        base = base * base % MODULUS;
+        exp >>= 1;
+    }
+    res
+}
+
+/// How many times 7 has to be multiplied by itself to give `key`, by baby-step giant-step.
+/// 7 generates every nonzero number below the modulus, so there's always an answer.
+fn discrete_log(key: u64) -> u64 {
+    let m = (MODULUS - 1).isqrt() + 1;
+
+    let mut baby = rustc_hash::FxHashMap::default();
+    let mut power = 1;
+    for j in 0..m {
+        baby.entry(power).or_insert(j);
+        power = power * SUBJECT % MODULUS;
+    }
+
+    // 7^-m, since 7^(p - 1) is 1.
+    let giant = pow_mod(SUBJECT, MODULUS - 1 - m);
+    let mut target = key;
+    for i in 0..m {
+        if let Some(&j) = baby.get(&target) {
+            return i * m + j;
+        }
+        target = target * giant % MODULUS;
+    }
+
+    unreachable!("7 generates every key")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn d25p1() {
+        assert_eq!(
+            Day25::part1(&Day25::parse("5764801\n17807724").unwrap()).unwrap(),
+            14_897_079
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{
+    day_solver::DaySolver,
+    error::{Error, Result},
+    util::*,
+};
+
+pub struct Day3;
+
+impl DaySolver<'_> for Day3 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 3;
+
+    type Parsed = Grid<bool>;
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        let input = input.as_bytes();
+        if let Some(i) = input
+            .iter()
+            .position(|&c| !matches!(c, b'.' | b'#' | b'\n'))
+        {
+            return Err(Error::parse(input, &input[i..=i], "`.` or `#`"));
+        }
+        if input.first().is_none_or(|&c| c == b'\n') {
+            return Err(Error::parse(input, &input[..0], "a map row"));
+        }
+
+        let lines = || input.split(|&x| x == b'\n');
+        Grid::try_from_rows(lines().map(|x| x.iter().map(|&c| Ok(c == b'#'))), |row| {
+            Error::parse(
+                input,
+                lines().nth(row).unwrap(),
+                "a row as long as the first",
+            )
+        })
+    }
+
+    fn part1(map: &Self::Parsed) -> Result<Self::Output> {
+        Ok(run_slope(map, (3, 1)))
+    }
+
+    fn part2(map: &Self::Parsed) -> Result<Self::Output> {
+        Ok([(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
+            .iter()
+            .map(|&s| run_slope(map, s))
+            .product())
+    }
+}
+
+fn run_slope<'a>(
+    map: &<Day3 as DaySolver>::Parsed,
+    (x_count, y_count): (usize, usize),
+) -> <Day3 as DaySolver<'a>>::Output {
+    let start = (0, 0);
+    std::iter::once(start)
+        .chain(map.ray(
+            start,
+            (y_count as isize, x_count as isize),
+            Edges::WrapColumns,
+        ))
+        .filter(|&p| map[p])
+        .count() as <Day3 as DaySolver<'_>>::Output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn d3p1() {
+        assert_eq!(
+            Day3::part1(
+                &Day3::parse(
+                    "..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            7
+        );
+    }
+
+    #[test]
+    fn d3p2() {
+        assert_eq!(
+            Day3::part2(
+                &Day3::parse(
+                    "..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            336
+        );
+    }
+
+    #[test]
+    fn d3bad() {
+        let e = Day3::parse("..#\n.#\n#..").err().unwrap();
+        assert!(matches!(
+            e.kind(),
+            crate::error::ErrorKind::Parse {
+                line: 2,
+                column: 1,
+                ..
+            }
+        ));
+    }
+}
@@ -1,4 +1,10 @@
-use crate::{day_solver::DaySolver, util::*};
+use std::ops::RangeInclusive;
+
+use crate::{
+    day_solver::DaySolver,
+    error::{Error, Result},
+    util::*,
+};
 
 pub struct Day4;
 
@@ -14,10 +20,13 @@ pub struct Passport<'a> {
 }
 
 impl<'a> DaySolver<'a> for Day4 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 4;
+
     type Parsed = Vec<Passport<'a>>;
     type Output = usize;
 
-    fn parse(input: &'a str) -> Self::Parsed {
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
         let mut pass = PassportBuilder::default();
         let mut results = Vec::new();
 
@@ -29,6 +38,9 @@ impl<'a> DaySolver<'a> for Day4 {
                 pass = PassportBuilder::default();
             } else {
                 const COLON: usize = 3;
+                if field.get(COLON) != Some(&b':') {
+                    return Err(Error::parse(input, field, "a `key:value` field"));
+                }
                 let key = &field[..COLON];
                 let value = &field[COLON + 1..];
 
@@ -41,7 +53,7 @@ impl<'a> DaySolver<'a> for Day4 {
                     b"ecl" => pass.eye_color(value),
                     b"pid" => pass.pass_id(value),
                     b"cid" => &mut pass,
-                    _ => unreachable!(),
+                    _ => return Err(Error::parse(input, key, "a passport field")),
                 };
             }
         }
@@ -50,36 +62,38 @@ impl<'a> DaySolver<'a> for Day4 {
             results.push(p);
         }
 
-        results
+        Ok(results)
     }
 
-    fn part1(data: Self::Parsed) -> Self::Output {
-        data.len()
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        Ok(data.len())
     }
 
-    fn part2(data: Self::Parsed) -> Self::Output {
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
         let mut count = 0;
 
         for p in data {
-            if !(1920..=2002).contains(&p.birth_year.parse().unwrap()) {
+            if !in_range(p.birth_year, 1920..=2002) {
                 continue;
             }
 
-            if !(2010..=2020).contains(&p.issue_year.parse().unwrap()) {
+            if !in_range(p.issue_year, 2010..=2020) {
                 continue;
             }
 
-            if !(2020..=2030).contains(&p.exp_year.parse().unwrap()) {
+            if !in_range(p.exp_year, 2020..=2030) {
                 continue;
             }
 
-            let height_num_index = p.height.len() - 2;
+            let Some(height_num_index) = p.height.len().checked_sub(2) else {
+                continue;
+            };
             let height_range = match &p.height[height_num_index..] {
                 b"in" => 59..=76,
                 b"cm" => 150..=193,
                 _ => continue,
             };
-            if !height_range.contains(&p.height[..height_num_index].parse().unwrap()) {
+            if !in_range(&p.height[..height_num_index], height_range) {
                 continue;
             }
 
@@ -102,10 +116,14 @@ impl<'a> DaySolver<'a> for Day4 {
             count += 1;
         }
 
-        count
+        Ok(count)
     }
 }
 
+fn in_range(value: &[u8], range: RangeInclusive<u16>) -> bool {
+    value.parse().is_ok_and(|v| range.contains(&v))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -113,8 +131,9 @@ mod tests {
     #[test]
     fn d4p1() {
         assert_eq!(
-            Day4::part1(Day4::parse(
-                "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+            Day4::part1(
+                &Day4::parse(
+                    "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
 byr:1937 iyr:2017 cid:147 hgt:183cm
 
 iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
@@ -127,7 +146,10 @@ hgt:179cm
 
 hcl:#cfa07d eyr:2025 pid:166559648
 iyr:2011 ecl:brn hgt:59in"
-            )),
+                )
+                .unwrap()
+            )
+            .unwrap(),
             2
         );
     }
@@ -135,8 +157,9 @@ iyr:2011 ecl:brn hgt:59in"
     #[test]
     fn d4p2() {
         assert_eq!(
-            Day4::part2(Day4::parse(
-                "eyr:1972 cid:100
+            Day4::part2(
+                &Day4::parse(
+                    "eyr:1972 cid:100
 hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
 
 iyr:2019
@@ -149,13 +172,17 @@ ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
 hgt:59cm ecl:zzz
 eyr:2038 hcl:74454a iyr:2023
 pid:3556412378 byr:2007"
-            )),
+                )
+                .unwrap()
+            )
+            .unwrap(),
             0
         );
 
         assert_eq!(
-            Day4::part2(Day4::parse(
-                "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
+            Day4::part2(
+                &Day4::parse(
+                    "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
 hcl:#623a2f
 
 eyr:2029 ecl:blu cid:129 byr:1989
@@ -167,7 +194,10 @@ pid:545766238 ecl:hzl
 eyr:2022
 
 iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719"
-            )),
+                )
+                .unwrap()
+            )
+            .unwrap(),
             4
         );
     }
@@ -0,0 +1,95 @@
+use std::io::BufRead;
+
+use crate::{
+    day_solver::{DaySolver, StreamingDaySolver},
+    error::{Error, Result},
+    util::for_each_line,
+};
+
+pub struct Day5;
+
+impl DaySolver<'_> for Day5 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 5;
+
+    type Parsed = Vec<Self::Output>;
+    type Output = u16;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input
+            .as_bytes()
+            .split(|&x| x == b'\n')
+            .map(|line| seat(input.as_bytes(), line))
+            .collect()
+    }
+
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        data.iter()
+            .copied()
+            .max()
+            .ok_or_else(|| Error::no_solution("no boarding passes"))
+    }
+
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        let mut min = Self::Output::MAX;
+        let mut max = Self::Output::MIN;
+        let mut total = 0;
+
+        for &x in data {
+            min = std::cmp::min(min, x);
+            max = std::cmp::max(max, x);
+            total ^= x;
+        }
+
+        Ok((min..=max).fold(0, |a, x| a ^ x) ^ total)
+    }
+}
+
+impl StreamingDaySolver<'_> for Day5 {
+    fn parse_reader(reader: impl BufRead) -> Result<Self::Parsed> {
+        let mut res = Vec::new();
+        for_each_line(reader, |_, line| {
+            res.push(seat(line, line)?);
+            Ok(())
+        })?;
+        Ok(res)
+    }
+}
+
+/// The seat ID a boarding pass encodes.
+fn seat(input: &[u8], line: &[u8]) -> Result<u16> {
+    if line.len() != 10 {
+        return Err(Error::parse(input, line, "a 10 character boarding pass"));
+    }
+
+    let mut num = 0;
+    for (i, x) in line.iter().enumerate() {
+        let bit = match x {
+            b'F' | b'L' => 0,
+            b'B' | b'R' => 1,
+            _ => return Err(Error::parse(input, &line[i..=i], "`F`, `B`, `L` or `R`")),
+        };
+
+        num = (num << 1) + bit;
+    }
+    Ok(num)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn d5p1() {
+        assert_eq!(
+            Day5::parse(
+                "FBFBBFFRLR
+BFFFBBFRRR
+FFFBBBFRRR
+BBFFBBFRLL"
+            )
+            .unwrap(),
+            &[357, 567, 119, 820]
+        );
+    }
+}
@@ -1,4 +1,7 @@
-use crate::day_solver::DaySolver;
+use crate::{
+    day_solver::DaySolver,
+    error::{Error, Result},
+};
 
 pub struct Day6;
 
@@ -12,10 +15,13 @@ pub struct Group {
 }
 
 impl DaySolver<'_> for Day6 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 6;
+
     type Parsed = Vec<Group>;
     type Output = usize;
 
-    fn parse(input: &str) -> Self::Parsed {
+    fn parse(input: &str) -> Result<Self::Parsed> {
         let mut results = Vec::new();
         let mut answers = [0; 26];
         let mut person_count = 0;
@@ -31,7 +37,14 @@ impl DaySolver<'_> for Day6 {
                 person_count = 0;
             } else {
                 person_count += 1;
-                for &answer in line {
+                for (i, &answer) in line.iter().enumerate() {
+                    if !answer.is_ascii_lowercase() {
+                        return Err(Error::parse(
+                            input,
+                            &line[i..=i],
+                            "a question from `a` to `z`",
+                        ));
+                    }
                     answers[(answer - b'a') as usize] += 1;
                 }
             }
@@ -41,24 +54,24 @@ impl DaySolver<'_> for Day6 {
             person_count,
             answers,
         });
-        results
+        Ok(results)
     }
 
-    fn part1(data: Self::Parsed) -> Self::Output {
-        run(data, |a, _| a > 0)
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        Ok(run(data, |a, _| a > 0))
     }
 
-    fn part2(data: Self::Parsed) -> Self::Output {
-        run(data, |a, g| a == g.person_count)
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        Ok(run(data, |a, g| a == g.person_count))
     }
 }
 
 fn run<'a>(
-    data: <Day6 as DaySolver>::Parsed,
+    data: &<Day6 as DaySolver>::Parsed,
     f: impl Fn(N, &Group) -> bool,
 ) -> <Day6 as DaySolver<'a>>::Output {
-    data.into_iter()
-        .map(|g| g.answers.iter().filter(|&&a| f(a, &g)).count())
+    data.iter()
+        .map(|g| g.answers.iter().filter(|&&a| f(a, g)).count())
         .sum()
 }
 
@@ -69,8 +82,9 @@ mod tests {
     #[test]
     fn d6p1() {
         assert_eq!(
-            Day6::part1(Day6::parse(
-                "abc
+            Day6::part1(
+                &Day6::parse(
+                    "abc
 
 a
 b
@@ -85,7 +99,10 @@ a
 a
 
 b"
-            )),
+                )
+                .unwrap()
+            )
+            .unwrap(),
             11
         );
     }
@@ -93,8 +110,9 @@ b"
     #[test]
     fn d6p2() {
         assert_eq!(
-            Day6::part2(Day6::parse(
-                "abc
+            Day6::part2(
+                &Day6::parse(
+                    "abc
 
 a
 b
@@ -109,7 +127,10 @@ a
 a
 
 b"
-            )),
+                )
+                .unwrap()
+            )
+            .unwrap(),
             6
         );
     }
@@ -0,0 +1,334 @@
+use std::{collections::VecDeque, convert::TryFrom};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use petgraph::{graph::NodeIndex, visit::EdgeRef, EdgeDirection::*, Graph};
+
+use crate::{
+    day_solver::DaySolver,
+    error::{Error, Result},
+    util::*,
+};
+
+pub struct Day7;
+
+#[derive(Copy, Clone, Eq, Hash, PartialEq)]
+pub struct Bag<'a> {
+    adjective: &'a [u8],
+    color: &'a [u8],
+}
+
+const MY_BAG: Bag<'static> = Bag {
+    adjective: b"shiny",
+    color: b"gold",
+};
+
+impl<'a> DaySolver<'a> for Day7 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 7;
+
+    type Parsed = (Graph<Bag<'a>, u16>, NodeIndex);
+    type Output = usize;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        let mut graph = Graph::new();
+        let mut indexes = FxHashMap::default();
+        let mut my_bag_index = NodeIndex::end();
+        // the inner bag's first word for every edge, to point at when the rules loop
+        let mut inner_words = Vec::new();
+
+        let mut words = input.as_bytes().split(|&x| x == b'\n' || x == b' ');
+        let mut next_word = |expected| words.next().ok_or_else(|| Error::eof(input, expected));
+
+        while let Ok(adjective) = next_word("a bag") {
+            let color = next_word("a bag color")?;
+
+            let outside_bag = Bag { adjective, color };
+            let outside_index = *indexes
+                .entry(outside_bag)
+                .or_insert_with(|| graph.add_node(outside_bag));
+
+            if outside_bag == MY_BAG {
+                my_bag_index = outside_index;
+            }
+
+            // skip "bags contain"
+            next_word("`bags`")?;
+            next_word("`contain`")?;
+
+            loop {
+                let count_word = next_word("a bag count")?;
+
+                let count = if count_word == b"no" {
+                    // skip "other bags."
+                    next_word("`other`")?;
+                    next_word("`bags.`")?;
+                    break;
+                } else {
+                    count_word.parse_in(input)?
+                };
+
+                let inner_adjective = next_word("a bag")?;
+                let inner_color = next_word("a bag color")?;
+                let inner_bag = Bag {
+                    adjective: inner_adjective,
+                    color: inner_color,
+                };
+                let inner_index = *indexes
+                    .entry(inner_bag)
+                    .or_insert_with(|| graph.add_node(inner_bag));
+
+                graph.add_edge(outside_index, inner_index, count);
+                inner_words.push(inner_adjective);
+
+                // bags[,.]
+                let bags = next_word("`bags,` or `bags.`")?;
+                match bags.last() {
+                    Some(b'.') => break,
+                    Some(b',') => {}
+                    _ => return Err(Error::parse(input, bags, "`bags,` or `bags.`")),
+                }
+            }
+        }
+
+        if my_bag_index == NodeIndex::end() {
+            return Err(Error::eof(input, "a rule for shiny gold bags"));
+        }
+
+        if let Some(word) = find_cycle(&graph, my_bag_index, &inner_words) {
+            return Err(Error::parse(
+                input,
+                word,
+                "a bag that doesn't end up inside itself",
+            ));
+        }
+
+        Ok((graph, my_bag_index))
+    }
+
+    fn part1((graph, my_bag): &Self::Parsed) -> Result<Self::Output> {
+        let mut queue = VecDeque::with_capacity(graph.node_count());
+        queue.push_back(*my_bag);
+
+        let mut seen = FxHashSet::with_capacity_and_hasher(graph.node_count(), Default::default());
+
+        while let Some(nx) = queue.pop_front() {
+            if seen.insert(nx) {
+                for n in graph.neighbors_directed(nx, Incoming) {
+                    queue.push_back(n);
+                }
+            }
+        }
+
+        Ok(seen.len() - 1)
+    }
+
+    fn part2((graph, my_bag): &Self::Parsed) -> Result<Self::Output> {
+        let count = bags_inside(graph, *my_bag)
+            .ok_or_else(|| Error::no_solution("too many bags to count"))?;
+
+        Self::Output::try_from(count).map_err(|_| Error::no_solution("too many bags to count"))
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum Visit {
+    Unseen,
+    Active,
+    Done,
+}
+
+/// A bag that can end up inside itself holds infinitely many bags, so finds the rule that closes
+/// a loop below `start`, if there is one.
+fn find_cycle<'a>(
+    graph: &Graph<Bag, u16>,
+    start: NodeIndex,
+    words: &[&'a [u8]],
+) -> Option<&'a [u8]> {
+    let mut state = vec![Visit::Unseen; graph.node_count()];
+    state[start.index()] = Visit::Active;
+    // The bags being searched, outermost first, with the rules still to follow from each.
+    let mut stack = vec![(start, graph.edges(start))];
+
+    while let Some((nx, edges)) = stack.last_mut() {
+        if let Some(er) = edges.next() {
+            let target = er.target();
+            match state[target.index()] {
+                Visit::Active => return Some(words[er.id().index()]),
+                Visit::Unseen => {
+                    state[target.index()] = Visit::Active;
+                    stack.push((target, graph.edges(target)));
+                }
+                Visit::Done => {}
+            }
+        } else {
+            state[nx.index()] = Visit::Done;
+            stack.pop();
+        }
+    }
+
+    None
+}
+
+/// Counts the bags inside `start`, working up from the bags with nothing inside them. `None` if the
+/// count doesn't fit.
+fn bags_inside(graph: &Graph<Bag, u16>, start: NodeIndex) -> Option<u64> {
+    let mut inside: Vec<Option<u64>> = vec![None; graph.node_count()];
+    // Bags waiting on the counts of the bags inside them, which are above them.
+    let mut stack = vec![start];
+
+    while let Some(&nx) = stack.last() {
+        let counted = stack.len();
+        stack.extend(graph.neighbors(nx).filter(|n| inside[n.index()].is_none()));
+        if stack.len() > counted {
+            continue;
+        }
+
+        stack.pop();
+        let mut count = 0u64;
+        for er in graph.edges(nx) {
+            let each = inside[er.target().index()]?.checked_add(1)?;
+            count = count.checked_add(each.checked_mul(u64::from(*er.weight()))?)?;
+        }
+        inside[nx.index()] = Some(count);
+    }
+
+    inside[start.index()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn d7p1() {
+        assert_eq!(
+            Day7::part1(
+                &Day7::parse(
+                    "light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags."
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            4
+        );
+    }
+
+    #[test]
+    fn d7p2() {
+        assert_eq!(
+            Day7::part2(
+                &Day7::parse(
+                    "light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags."
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            32
+        );
+
+        assert_eq!(
+            Day7::part2(
+                &Day7::parse(
+                    "shiny gold bags contain 2 dark red bags.
+dark red bags contain 2 dark orange bags.
+dark orange bags contain 2 dark yellow bags.
+dark yellow bags contain 2 dark green bags.
+dark green bags contain 2 dark blue bags.
+dark blue bags contain 2 dark violet bags.
+dark violet bags contain no other bags."
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            126
+        );
+    }
+
+    #[test]
+    fn wide_counts() {
+        assert_eq!(
+            Day7::part2(
+                &Day7::parse(
+                    "shiny gold bags contain 300 dark red bags.
+dark red bags contain 300 light blue bags.
+light blue bags contain no other bags."
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            90300
+        );
+    }
+
+    #[test]
+    fn bag_inside_itself() {
+        let input = "shiny gold bags contain 1 dark red bag.
+dark red bags contain 2 shiny gold bags, 1 light blue bag.
+light blue bags contain no other bags.";
+        let error = Day7::parse(input).err().unwrap();
+        assert!(matches!(
+            error.kind(),
+            crate::error::ErrorKind::Parse { line: 2, .. }
+        ));
+
+        // a loop that shiny gold can't reach doesn't matter
+        let parsed = Day7::parse(
+            "shiny gold bags contain 1 light blue bag.
+light blue bags contain no other bags.
+dark red bags contain 1 dark red bag, 1 shiny gold bag.",
+        )
+        .unwrap();
+        assert_eq!(Day7::part2(&parsed).unwrap(), 1);
+    }
+
+    #[test]
+    fn deep_nesting() {
+        use std::fmt::Write;
+
+        let depth = 100_000;
+        // Spelt last digit first, so the names don't all start the same and hash alike.
+        let name = |i: usize| -> String {
+            (0..4)
+                .map(|d| (b'a' + (i / 26_usize.pow(d) % 26) as u8) as char)
+                .collect()
+        };
+        let mut input = format!(
+            "shiny gold bags contain 1 {first} red bag.\n",
+            first = name(0)
+        );
+        for i in 1..depth {
+            writeln!(
+                input,
+                "{outer} red bags contain 1 {inner} red bag.",
+                outer = name(i - 1),
+                inner = name(i)
+            )
+            .unwrap();
+        }
+        write!(
+            input,
+            "{last} red bags contain no other bags.",
+            last = name(depth - 1)
+        )
+        .unwrap();
+
+        assert_eq!(Day7::part2(&Day7::parse(&input).unwrap()).unwrap(), depth);
+    }
+}
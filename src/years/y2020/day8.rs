@@ -0,0 +1,363 @@
+use std::io::BufRead;
+
+use crate::{
+    day_solver::{DaySolver, StreamingDaySolver},
+    error::{Error, Result},
+    util::*,
+};
+
+pub struct Day8;
+
+type N = i16;
+
+#[derive(Copy, Clone)]
+pub enum Instruction {
+    Acc(N),
+    Jmp(N),
+    Nop(N),
+}
+
+use Instruction::*;
+
+impl DaySolver<'_> for Day8 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 8;
+
+    type Parsed = Vec<Instruction>;
+    type Output = i64;
+
+    const CONSUMES: [bool; 2] = [false, true];
+    const SOLVES_BOTH: bool = true;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        let len = bytecount::count(input.as_bytes(), b'\n') + 1;
+
+        input
+            .as_bytes()
+            .split(|&x| x == b'\n')
+            .enumerate()
+            .map(|(i, l)| {
+                let instruction = parse_instruction(input.as_bytes(), l)?;
+
+                if let Jmp(x) = instruction {
+                    if !(0..=len as isize).contains(&(i as isize + x as isize)) {
+                        return Err(Error::parse(input, &l[4..], "a jump within the program"));
+                    }
+                }
+
+                Ok(instruction)
+            })
+            .collect()
+    }
+
+    fn part1(program: &Self::Parsed) -> Result<Self::Output> {
+        Ok(run(program)?.1)
+    }
+
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        Self::part2_owned(data.clone())
+    }
+
+    fn part2_owned(program: Self::Parsed) -> Result<Self::Output> {
+        let trace = run(&program)?.2;
+        repair(program, &trace)
+    }
+
+    fn solve_both(program: &Self::Parsed) -> Result<(Self::Output, Self::Output)> {
+        let (_, acc, trace) = run(program)?;
+        Ok((acc, repair(program.clone(), &trace)?))
+    }
+}
+
+impl StreamingDaySolver<'_> for Day8 {
+    fn parse_reader(reader: impl BufRead) -> Result<Self::Parsed> {
+        let mut res = Vec::new();
+        // The furthest any jump lands, and what to report if that turns out to be past the end.
+        let mut furthest: Option<(isize, Error)> = None;
+
+        for_each_line(reader, |i, l| {
+            let instruction = parse_instruction(l, l)?;
+
+            if let Jmp(x) = instruction {
+                let target = i as isize + x as isize;
+                let outside = || Error::parse(l, &l[4..], "a jump within the program");
+                if target < 0 {
+                    return Err(outside());
+                }
+                if furthest.as_ref().is_none_or(|&(f, _)| target > f) {
+                    furthest = Some((target, outside().on_line(i + 1)));
+                }
+            }
+
+            res.push(instruction);
+            Ok(())
+        })?;
+
+        match furthest {
+            Some((target, e)) if target > res.len() as isize => Err(e),
+            _ => Ok(res),
+        }
+    }
+}
+
+fn parse_instruction(input: &[u8], l: &[u8]) -> Result<Instruction> {
+    let (Some(op), Some(num)) = (l.get(0..3), l.get(4..)) else {
+        return Err(Error::parse(input, l, "an instruction"));
+    };
+
+    Ok(match op {
+        b"acc" => Acc(num.parse_in(input)?),
+        b"jmp" => Jmp(num.parse_in(input)?),
+        b"nop" => Nop(num.parse_in(input)?),
+        _ => return Err(Error::parse(input, op, "`acc`, `jmp` or `nop`")),
+    })
+}
+
+/// Fixes the one corrupted instruction, given which ones the broken program ran, and returns
+/// what the accumulator ends up as.
+#[allow(clippy::needless_continue, clippy::redundant_else)]
+fn repair(mut program: <Day8 as DaySolver>::Parsed, trace: &[bool]) -> Result<i64> {
+    let no_swap = || Error::no_solution("no single swap makes the program terminate");
+
+    let mut potential_landing_spots = vec![false; program.len() + 1];
+    let mut i = program.len();
+    loop {
+        potential_landing_spots[i] = true;
+        i = i.checked_sub(1).ok_or_else(no_swap)?;
+
+        if let Jmp(x) = program[i] {
+            if x < 0 {
+                break;
+            }
+        }
+    }
+
+    let start = i;
+    let swap = if trace[i] {
+        //println!("Found last negative jmp.");
+        i
+    } else {
+        loop {
+            i = i.checked_sub(1).ok_or_else(no_swap)?;
+
+            if potential_landing_spots[i] {
+                continue;
+            } else if let Nop(x) = program[i] {
+                let target = jump(i, x).and_then(|t| potential_landing_spots.get(t));
+                if trace[i] && target == Some(&true) {
+                    //println!("Found nop to jmp.");
+                    break i;
+                }
+            } else if let Jmp(x) = program[i] {
+                if !trace[i]
+                    && jump(i, x).and_then(|t| potential_landing_spots.get(t)) == Some(&true)
+                    && !potential_landing_spots[i]
+                {
+                    let mut j = i.checked_sub(1).ok_or_else(no_swap)?;
+                    loop {
+                        if matches!(program[j], Jmp(_)) {
+                            break;
+                        }
+                        j = j.checked_sub(1).ok_or_else(no_swap)?;
+                    }
+
+                    if trace[j] {
+                        //println!("Found jmp preceded by hit jmp.");
+                        break j;
+                    } else {
+                        //println!("Found jmp preceded by unhit jmp.");
+                        potential_landing_spots[j + 1..=i].iter_mut().for_each(|a| {
+                            *a = true;
+                        });
+                        i = start;
+                    }
+                }
+            }
+        }
+    };
+
+    program[swap] = match program[swap] {
+        Acc(_) => return Err(no_swap()),
+        Jmp(x) => Nop(x),
+        Nop(x) => Jmp(x),
+    };
+    let res = run(&program)?;
+    if res.0 {
+        Ok(res.1)
+    } else {
+        Err(no_swap())
+    }
+}
+
+/// Where a jump of `x` from `pc` lands, if that's not before the start.
+fn jump(pc: usize, x: N) -> Option<usize> {
+    pc.checked_add_signed(x.into())
+}
+
+/// Runs the program until it either ends or loops. Returns whether it ended, the accumulator
+/// and which instructions ran.
+fn run(program: &<Day8 as DaySolver>::Parsed) -> Result<(bool, i64, Vec<bool>)> {
+    let mut seen = vec![false; program.len()];
+    let mut acc = 0;
+    let mut pc = 0;
+
+    loop {
+        if pc == program.len() {
+            break Ok((true, acc, seen));
+        }
+
+        if seen[pc] {
+            break Ok((false, acc, seen));
+        }
+        seen[pc] = true;
+
+        match program[pc] {
+            Acc(x) => {
+                acc += i64::from(x);
+                pc += 1;
+            }
+            Jmp(x) => {
+                pc = jump(pc, x)
+                    .filter(|&t| t <= program.len())
+                    .ok_or_else(|| Error::no_solution("the program jumps outside itself"))?;
+            }
+            Nop(_) => {
+                pc += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn d8p1() {
+        assert_eq!(
+            Day8::part1(
+                &Day8::parse(
+                    "nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            5
+        );
+    }
+
+    #[test]
+    fn d8p2() {
+        assert_eq!(
+            Day8::part2(
+                &Day8::parse(
+                    "nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            8
+        );
+    }
+
+    #[test]
+    fn d8both() {
+        assert_eq!(
+            Day8::solve_both(
+                &Day8::parse(
+                    "nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            (5, 8)
+        );
+    }
+
+    #[test]
+    fn d8bad() {
+        let e = Day8::parse("nop +0\nfoo +1\njmp -2").err().unwrap();
+        assert!(matches!(
+            e.kind(),
+            crate::error::ErrorKind::Parse {
+                line: 2,
+                column: 1,
+                ..
+            }
+        ));
+
+        assert!(Day8::parse("nop +0\njmp +5").is_err());
+    }
+
+    #[test]
+    fn d8stream() {
+        let e = Day8::parse_reader(&b"nop +0\n\nfoo +1\n"[..])
+            .err()
+            .unwrap();
+        assert!(matches!(
+            e.kind(),
+            crate::error::ErrorKind::Parse {
+                line: 2,
+                column: 1,
+                ..
+            }
+        ));
+
+        let e = Day8::parse_reader(&b"nop +0\njmp +5\njmp -1\n\n"[..])
+            .err()
+            .unwrap();
+        assert!(matches!(
+            e.kind(),
+            crate::error::ErrorKind::Parse {
+                line: 2,
+                column: 5,
+                ..
+            }
+        ));
+
+        assert_eq!(
+            Day8::part1(&Day8::parse_reader(&b"nop +0\njmp -1\n\n"[..]).unwrap()).unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn d8long() {
+        let program = "nop +0\n".repeat(39_999) + "jmp +1";
+        assert_eq!(Day8::part1(&Day8::parse(&program).unwrap()).unwrap(), 0);
+        assert_eq!(
+            Day8::part1(&Day8::parse_reader(program.as_bytes()).unwrap()).unwrap(),
+            0
+        );
+
+        let program = "acc +30000\n".repeat(40_000) + "jmp -1";
+        assert_eq!(
+            Day8::part1(&Day8::parse(&program).unwrap()).unwrap(),
+            1_200_000_000
+        );
+    }
+}
@@ -0,0 +1,208 @@
+use std::{cmp::Ordering::*, io::BufRead};
+
+use crate::{
+    day_solver::{DaySolver, StreamingDaySolver},
+    error::{Error, Result},
+    util::*,
+};
+
+pub struct Day9;
+
+type N = u64;
+
+impl DaySolver<'_> for Day9 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 9;
+
+    type Parsed = Vec<N>;
+    type Output = N;
+
+    const SOLVES_BOTH: bool = true;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input
+            .as_bytes()
+            .split(|&x| x == b'\n')
+            .map(|x| x.parse_in(input))
+            .collect()
+    }
+
+    fn part1(data: &Self::Parsed) -> Result<Self::Output> {
+        do_part_1(data, 25)
+    }
+
+    fn part2(data: &Self::Parsed) -> Result<Self::Output> {
+        do_part_2(data, 25)
+    }
+
+    fn solve_both(data: &Self::Parsed) -> Result<(Self::Output, Self::Output)> {
+        do_both(data, 25)
+    }
+}
+
+impl StreamingDaySolver<'_> for Day9 {
+    fn parse_reader(reader: impl BufRead) -> Result<Self::Parsed> {
+        let mut res = Vec::new();
+        for_each_line(reader, |_, line| {
+            res.push(line.parse_in(line)?);
+            Ok(())
+        })?;
+        Ok(res)
+    }
+}
+
+fn do_part_1(data: &<Day9 as DaySolver>::Parsed, size: usize) -> Result<N> {
+    for w in data.windows(size + 1) {
+        let (&value, window) = w.split_last().unwrap();
+
+        if !window
+            .iter()
+            .any(|&v| value > v && window.contains(&(value - v)))
+        {
+            return Ok(value);
+        }
+    }
+
+    Err(Error::no_solution("every number is a sum of two before it"))
+}
+
+fn do_part_2(data: &<Day9 as DaySolver>::Parsed, size: usize) -> Result<N> {
+    find_weakness(data, do_part_1(data, size)?)
+}
+
+fn do_both(data: &<Day9 as DaySolver>::Parsed, size: usize) -> Result<(N, N)> {
+    let value = do_part_1(data, size)?;
+    Ok((value, find_weakness(data, value)?))
+}
+
+/// Sums the ends of the contiguous range that adds up to `value`.
+fn find_weakness(data: &<Day9 as DaySolver>::Parsed, value: N) -> Result<N> {
+    let (mut i, mut j) = (0, 0);
+    let mut sum = data[0];
+    loop {
+        match sum.cmp(&value) {
+            // A number on its own isn't a range.
+            Equal if j > i => {
+                let range = &data[i..=j];
+                break Ok(range.iter().min().unwrap() + range.iter().max().unwrap());
+            }
+            Less | Equal => {
+                j += 1;
+                sum += data
+                    .get(j)
+                    .ok_or_else(|| Error::no_solution("no contiguous range sums to the target"))?;
+            }
+            Greater => {
+                sum -= data[i];
+                i += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn d9p1() {
+        assert_eq!(
+            do_part_1(
+                &Day9::parse(
+                    "35
+20
+15
+25
+47
+40
+62
+55
+65
+95
+102
+117
+150
+182
+127
+219
+299
+277
+309
+576"
+                )
+                .unwrap(),
+                5
+            )
+            .unwrap(),
+            127
+        );
+    }
+
+    #[test]
+    fn d9p2() {
+        assert_eq!(
+            do_part_2(
+                &Day9::parse(
+                    "35
+20
+15
+25
+47
+40
+62
+55
+65
+95
+102
+117
+150
+182
+127
+219
+299
+277
+309
+576"
+                )
+                .unwrap(),
+                5
+            )
+            .unwrap(),
+            62
+        );
+    }
+
+    #[test]
+    fn d9both() {
+        assert_eq!(
+            do_both(
+                &Day9::parse(
+                    "35
+20
+15
+25
+47
+40
+62
+55
+65
+95
+102
+117
+150
+182
+127
+219
+299
+277
+309
+576"
+                )
+                .unwrap(),
+                5
+            )
+            .unwrap(),
+            (127, 62)
+        );
+    }
+}
@@ -0,0 +1,58 @@
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+use crate::registry::Solver;
+
+#[must_use]
+pub fn solvers() -> Vec<Solver> {
+    vec![
+        Solver::streaming::<day1::Day1>(),
+        Solver::streaming::<day2::Day2>(),
+        Solver::new::<day3::Day3>(),
+        Solver::new::<day4::Day4>(),
+        Solver::streaming::<day5::Day5>(),
+        Solver::new::<day6::Day6>(),
+        Solver::new::<day7::Day7>(),
+        Solver::streaming::<day8::Day8>(),
+        Solver::streaming::<day9::Day9>(),
+        Solver::new::<day10::Day10>(),
+        Solver::new::<day11::Day11>(),
+        Solver::streaming::<day12::Day12>(),
+        Solver::new::<day13::Day13>(),
+        Solver::streaming::<day14::Day14>(),
+        Solver::new::<day15::Day15>().slow(),
+        Solver::new::<day16::Day16>(),
+        Solver::new::<day17::Day17>().backends(day17::with_backend),
+        Solver::streaming::<day18::Day18>(),
+        Solver::new::<day19::Day19>(),
+        Solver::new::<day20::Day20>(),
+        Solver::new::<day21::Day21>(),
+        Solver::new::<day22::Day22>(),
+        Solver::new::<day23::Day23>().slow(),
+        Solver::streaming::<day24::Day24>(),
+        Solver::new::<day25::Day25>().partial(),
+    ]
+}
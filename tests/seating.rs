@@ -0,0 +1,83 @@
+//! Drives day 11's seat simulation from outside the crate, with a visibility rule of its own.
+
+use aoc::{
+    day_solver::DaySolver,
+    util::{Edges, Grid, Point, DIRECTIONS_8},
+    years::y2020::day11::{adjacent, visible, Day11, Position, Seating, Settled, Visibility},
+};
+
+const EXAMPLE: &str = "L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL";
+
+/// Pays attention to the seats two spots away in each direction, skipping over the next one.
+struct Knight;
+
+impl Visibility for Knight {
+    fn look(&self, seats: &Grid<Position>, p: Point, mut see: impl FnMut(Point)) {
+        for &by in &DIRECTIONS_8 {
+            let seat = seats
+                .step(p, by, Edges::Stop)
+                .and_then(|q| seats.step(q, by, Edges::Stop))
+                .filter(|&q| !matches!(seats[q], Position::Floor));
+            if let Some(seat) = seat {
+                see(seat);
+            }
+        }
+    }
+}
+
+#[test]
+fn puzzle_rules() {
+    let seats = Day11::parse(EXAMPLE).unwrap();
+
+    let mut seating = Seating::new(seats.clone(), &adjacent, 4);
+    assert_eq!(seating.run_until_stable(), Settled::Fixed { generation: 5 });
+    assert_eq!(seating.occupied(), 37);
+
+    let mut seating = Seating::new(seats, &visible, 5);
+    assert_eq!(seating.settle(), Settled::Fixed { generation: 6 });
+    assert_eq!(seating.occupied(), 26);
+}
+
+#[test]
+fn own_rule() {
+    let mut seating = Seating::new(Day11::parse(EXAMPLE).unwrap(), &Knight, 3);
+    assert!(seating.step());
+    assert_eq!(seating.generation(), 1);
+    assert_eq!(seating.seats().flat_iter().count(), 100);
+
+    // Seats two apart keep taking turns to fill up.
+    assert_eq!(
+        seating.settle(),
+        Settled::Cycle {
+            start: 2,
+            period: 2
+        }
+    );
+    assert!(matches!(
+        seating.clone().run_until_stable(),
+        Settled::Cycle { period: 2, .. }
+    ));
+    assert_eq!(seating.occupied(), 18);
+    assert_eq!(
+        seating.to_string(),
+        "L.L#.##.##
+LLLLLLL.L#
+L.L.L..L..
+LLLL.LL.L#
+L.L#.#L.##
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+#.L#L###.#
+L.LLLLL.L#"
+    );
+}